@@ -0,0 +1,646 @@
+use std::str::FromStr;
+use substreams::scalar::BigDecimal;
+
+const MAINNET_USDC_ADDRESS: &str = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+const MAINNET_USDT_ADDRESS: &str = "dac17f958d2ee523a2206206994597c13d831ec7";
+const MAINNET_DAI_ADDRESS: &str = "6b175474e89094c44da98b954eedeac495271d0f";
+const MAINNET_WETH_ADDRESS: &str = "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+const MAINNET_MINIMUM_ETH_LOCKED: &str = "52";
+const DEFAULT_MAX_PRICING_HOPS: usize = 3;
+
+// hard-coded tokens which have various behaviours but for which a UniswapV3 valid pool
+// exists, some are tokens which were migrated to new addresses
+const MAINNET_STATIC_TOKENS: [(&str, &str, &str, u64); 6] = [
+    ("e0b7927c4af23765cb51314a0e0521a9645f0e2a", "DGD", "DGD", 9),
+    ("7fc66500c84a76ad7e9c93437bfc5ac33e2ddae9", "AAVE", "Aave Token", 18),
+    ("eb9951021698b42e4399f9cbb6267aa35f82d59d", "LIF", "LIF", 18),
+    ("bdeb4b83251fb146687fa19d1c660f99411eefe3", "SVD", "savedroid", 18),
+    ("bb9bc244d798123fde783fcc1c72d3bb8c189413", "TheDAO", "TheDAO", 16),
+    ("38c6a68304cdefb9bec48bbfaaba5c5b47818bb2", "HPB", "HPBCoin", 18),
+];
+
+const MAINNET_STABLE_COINS: [&str; 6] = [
+    "6b175474e89094c44da98b954eedeac495271d0f", // DAI
+    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
+    "dac17f958d2ee523a2206206994597c13d831ec7", // USDT
+    "0000000000085d4780b73119b644ae5ecd22b376", // TUSD
+    "956f47f50a910163d8bf957cf5846d573e7f87ca", // FEI
+    "4dd28568d05f09b02220b09c2cb307bfd837cb95", // PRINTS
+];
+
+const MAINNET_WHITELIST_TOKENS: [&str; 21] = [
+    "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", // WETH
+    "6b175474e89094c44da98b954eedeac495271d0f", // DAI
+    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
+    "dac17f958d2ee523a2206206994597c13d831ec7", // USDT
+    "0000000000085d4780b73119b644ae5ecd22b376", // TUSD
+    "2260fac5e5542a773aa44fbcfedf7c193bc2c599", // WBTC
+    "5d3a536e4d6dbd6114cc1ead35777bab948e3643", // cDAI
+    "39aa39c021dfbae8fac545936693ac917d5e7563", // cUSDC
+    "86fadb80d8d2cff3c3680819e4da99c10232ba0f", // EBASE
+    "57ab1ec28d129707052df4df418d58a2d46d5f51", // sUSD
+    "9f8f72aa9304c8b593d555f12ef6589cc3a579a2", // MKR
+    "c00e94cb662c3520282e6f5717214004a7f26888", // COMP
+    "514910771af9ca656af840dff83e8264ecf986ca", // LINK
+    "c011a73ee8576fb46f5e1c5751ca3b9fe0af2a6f", // SNX
+    "0bc529c00c6401aef6d220be8c6ea1667f6ad93e", // YFI
+    "111111111117dc0aa78b770fa6a738034120c302", // 1INCH
+    "df5e0e81dff6faf3a7e52ba697820c5e32d806a8", // yCurv
+    "956f47f50a910163d8bf957cf5846d573e7f87ca", // FEI
+    "7d1afa7b718fb893db30a3abc0cfc608aacfebb0", // MATIC
+    "7fc66500c84a76ad7e9c93437bfc5ac33e2ddae9", // AAVE
+    "fe2e637202056d30016725477c5da089ab0a043a", // sETH2
+];
+
+/// Tokens and thresholds used to derive the ETH and USD prices, defaults to the mainnet subgraph values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PricingConfig {
+    /// wrapped native token, every token is priced against it
+    pub wrapped_native: String,
+    /// stable/wrapped native pools used to price the wrapped native token in USD
+    pub reference_pools: Vec<ReferencePool>,
+    pub stable_coins: Vec<String>,
+    pub whitelist_tokens: Vec<String>,
+    /// native amount a non whitelisted pool must hold to be used for pricing
+    pub minimum_native_locked: BigDecimal,
+    /// maximum number of pools between a token and the wrapped native token when no whitelisted pool prices it
+    pub max_pricing_hops: usize,
+    /// factor a pool price may move by from the previous block before its update is ignored, no guard when None
+    pub max_price_jump: Option<BigDecimal>,
+    /// tokens every token also gets a derived price in, on top of ETH and USD
+    pub quote_tokens: Vec<String>,
+}
+
+impl Default for PricingConfig {
+    fn default() -> Self {
+        PricingConfig {
+            wrapped_native: MAINNET_WETH_ADDRESS.to_string(),
+            reference_pools: vec![
+                ReferencePool::new("8ad599c3a0ff1de082011efddc58f1908eb6e6d8", MAINNET_USDC_ADDRESS), // USDC/WETH 0.3%
+                ReferencePool::new("88e6a0c2ddd26feeb64f039a2c41296fcb3f5640", MAINNET_USDC_ADDRESS), // USDC/WETH 0.05%
+                ReferencePool::new("4e68ccd3e89f51c3074ca5072bbac773960dfa36", MAINNET_USDT_ADDRESS), // WETH/USDT 0.3%
+                ReferencePool::new("11b815efb8f581194ae79006d24e0d814b7697f6", MAINNET_USDT_ADDRESS), // WETH/USDT 0.05%
+                ReferencePool::new("c2e9f25be6257c210d7adf0d4cd6e3e881ba25f8", MAINNET_DAI_ADDRESS),  // DAI/WETH 0.3%
+                ReferencePool::new("60594a405d53811d3bc4766596efd80fd545a270", MAINNET_DAI_ADDRESS),  // DAI/WETH 0.05%
+            ],
+            stable_coins: to_strings(&MAINNET_STABLE_COINS),
+            whitelist_tokens: to_strings(&MAINNET_WHITELIST_TOKENS),
+            minimum_native_locked: BigDecimal::from_str(MAINNET_MINIMUM_ETH_LOCKED).unwrap(),
+            max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+            max_price_jump: None,
+            quote_tokens: vec![],
+        }
+    }
+}
+
+impl PricingConfig {
+    /// Overrides the values set in the params, lists are comma separated, ex:
+    /// `wrapped_native=0x...&reference_pools=0x<pool>:0x<stable token>,...&stable_coins=0x...,0x...&whitelist_tokens=0x...`
+    /// `&minimum_native_locked=52&max_pricing_hops=3&max_price_jump=10&quote_tokens=0x...,0x...`
+    fn apply_params(&mut self, params: &String) {
+        for (key, value) in split_params(params) {
+            match key {
+                "wrapped_native" => self.wrapped_native = address(value),
+                "reference_pools" => self.reference_pools = reference_pools(value),
+                "stable_coins" => self.stable_coins = addresses(value),
+                "whitelist_tokens" => self.whitelist_tokens = addresses(value),
+                "quote_tokens" => self.quote_tokens = addresses(value),
+                "minimum_native_locked" => {
+                    self.minimum_native_locked = BigDecimal::from_str(value.trim())
+                        .unwrap_or_else(|_| panic!("invalid minimum_native_locked {value}"))
+                }
+                "max_price_jump" => {
+                    self.max_price_jump = Some(
+                        BigDecimal::from_str(value.trim()).unwrap_or_else(|_| panic!("invalid max_price_jump {value}")),
+                    )
+                }
+                "max_pricing_hops" => {
+                    self.max_pricing_hops = value
+                        .trim()
+                        .parse::<usize>()
+                        .unwrap_or_else(|_| panic!("invalid max_pricing_hops {value}"))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn is_whitelisted(&self, token_address: &str) -> bool {
+        self.whitelist_tokens.iter().any(|address| address == token_address)
+    }
+
+    pub fn is_stable_coin(&self, token_address: &str) -> bool {
+        self.stable_coins.iter().any(|address| address == token_address)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferencePool {
+    pub pool: String,
+    pub stable_token: String,
+}
+
+impl ReferencePool {
+    fn new(pool: &str, stable_token: &str) -> ReferencePool {
+        ReferencePool {
+            pool: pool.to_string(),
+            stable_token: stable_token.to_string(),
+        }
+    }
+}
+
+/// Token whose `decimals`, `name` and `symbol` are used when its calls fail
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaticToken {
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u64,
+}
+
+impl StaticToken {
+    fn new(address: &str, symbol: &str, name: &str, decimals: u64) -> StaticToken {
+        StaticToken {
+            address: address.to_string(),
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+            decimals,
+        }
+    }
+}
+
+/// Contracts and pricing of a Uniswap v3 deployment. The network is selected with the `network` param
+/// (`mainnet`, `arbitrum-one`, `optimism`, `polygon`, `base` or `bsc`, defaults to `mainnet`), and any of
+/// its values can be overridden with the `factory`, `position_manager`, `start_block`, `static_tokens`,
+/// `seeded_pools` and pricing params.
+/// Every module reading it must be given the same params.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetworkConfig {
+    pub network: String,
+    /// factory address as used in the `Factory` entity id, without the 0x prefix
+    pub factory: String,
+    pub position_manager: String,
+    /// block of the factory deployment, where the `Factory` and `Bundle` entities are created
+    pub start_block: u64,
+    /// pools created by the factory which are not indexed
+    pub ignored_pools: Vec<String>,
+    /// tokens of the network whose calls fail, see `StaticToken`
+    pub static_tokens: Vec<StaticToken>,
+    /// pools without a `PoolCreated` event, like the ones created before a regenesis, created at the start block
+    /// from their contract calls
+    pub seeded_pools: Vec<String>,
+    pub pricing: PricingConfig,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig::for_network("mainnet")
+    }
+}
+
+impl NetworkConfig {
+    pub fn for_network(network: &str) -> NetworkConfig {
+        match network {
+            "mainnet" => NetworkConfig {
+                network: network.to_string(),
+                factory: "1F98431c8aD98523631AE4a59f267346ea31F984".to_string(),
+                position_manager: "c36442b4a4522e871399cd717abdd847ab11fe88".to_string(),
+                start_block: 12369621,
+                ignored_pools: to_strings(&["8fe8d9bb8eeba3ed688069c3d6b556c9ca258248"]),
+                static_tokens: MAINNET_STATIC_TOKENS
+                    .iter()
+                    .map(|(address, symbol, name, decimals)| StaticToken::new(address, symbol, name, *decimals))
+                    .collect(),
+                seeded_pools: vec![],
+                pricing: PricingConfig::default(),
+            },
+            "arbitrum-one" => NetworkConfig {
+                network: network.to_string(),
+                factory: "1F98431c8aD98523631AE4a59f267346ea31F984".to_string(),
+                position_manager: "c36442b4a4522e871399cd717abdd847ab11fe88".to_string(),
+                start_block: 165,
+                ignored_pools: vec![],
+                static_tokens: vec![],
+                seeded_pools: vec![],
+                pricing: PricingConfig {
+                    wrapped_native: "82af49447d8a07e3bd95bd0d56f35241523fbab1".to_string(),
+                    reference_pools: vec![
+                        ReferencePool::new(
+                            "17c14d2c404d167802b16c450d3c99f88f2c4f4d", // WETH/USDC.e 0.3%
+                            "ff970a61a04b1ca14834a43f5de4533ebddb5cc8",
+                        ),
+                        ReferencePool::new(
+                            "c31e54c7a869b9fcbecc14363cf510d1c41fa443", // WETH/USDC.e 0.05%
+                            "ff970a61a04b1ca14834a43f5de4533ebddb5cc8",
+                        ),
+                        ReferencePool::new(
+                            "c6962004f452be9203591991d15f6b388e09e8d0", // WETH/USDC 0.05%
+                            "af88d065e77c8cc2239327c5edb3a432268e5831",
+                        ),
+                        ReferencePool::new(
+                            "641c00a822e8b671738d32a431a4fb6074e5c79d", // WETH/USDT 0.05%
+                            "fd086bc7cd5c481dcc9c85ebe478a1c0b69fcbb9",
+                        ),
+                    ],
+                    stable_coins: to_strings(&[
+                        "ff970a61a04b1ca14834a43f5de4533ebddb5cc8", // USDC.e
+                        "af88d065e77c8cc2239327c5edb3a432268e5831", // USDC
+                        "da10009cbd5d07dd0cecc66161fc93d7c9000da1", // DAI
+                        "fd086bc7cd5c481dcc9c85ebe478a1c0b69fcbb9", // USDT
+                    ]),
+                    whitelist_tokens: to_strings(&[
+                        "82af49447d8a07e3bd95bd0d56f35241523fbab1", // WETH
+                        "ff970a61a04b1ca14834a43f5de4533ebddb5cc8", // USDC.e
+                        "af88d065e77c8cc2239327c5edb3a432268e5831", // USDC
+                        "da10009cbd5d07dd0cecc66161fc93d7c9000da1", // DAI
+                        "fd086bc7cd5c481dcc9c85ebe478a1c0b69fcbb9", // USDT
+                        "2f2a2543b76a4166549f7aab2e75bef0aefc5b0f", // WBTC
+                        "912ce59144191c1204e64559fe8253a0e49e6548", // ARB
+                    ]),
+                    minimum_native_locked: BigDecimal::from_str("20").unwrap(),
+                    max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+                    max_price_jump: None,
+                    quote_tokens: vec![],
+                },
+            },
+            "optimism" => NetworkConfig {
+                network: network.to_string(),
+                factory: "1F98431c8aD98523631AE4a59f267346ea31F984".to_string(),
+                position_manager: "c36442b4a4522e871399cd717abdd847ab11fe88".to_string(),
+                // the state of the regenesis starts at the genesis block, where its pools are seeded
+                start_block: 0,
+                ignored_pools: vec![],
+                static_tokens: vec![],
+                seeded_pools: to_strings(&[
+                    // created before the November 2021 regenesis, the full list can be given with `seeded_pools`
+                    "03af20bdaaffb4cc0a521796a223f7d85e2aac31", // DAI/WETH 0.3%
+                    "85149247691df622eaf1a8bd0cafd40bc45154a9", // WETH/USDC.e 0.05%
+                ]),
+                pricing: PricingConfig {
+                    wrapped_native: "4200000000000000000000000000000000000006".to_string(),
+                    reference_pools: vec![
+                        ReferencePool::new(
+                            "03af20bdaaffb4cc0a521796a223f7d85e2aac31", // DAI/WETH 0.3%
+                            "da10009cbd5d07dd0cecc66161fc93d7c9000da1",
+                        ),
+                        ReferencePool::new(
+                            "85149247691df622eaf1a8bd0cafd40bc45154a9", // WETH/USDC.e 0.05%
+                            "7f5c764cbc14f9669b88837ca1490cca17c31607",
+                        ),
+                    ],
+                    stable_coins: to_strings(&[
+                        "da10009cbd5d07dd0cecc66161fc93d7c9000da1", // DAI
+                        "7f5c764cbc14f9669b88837ca1490cca17c31607", // USDC.e
+                        "0b2c639c533813f4aa9d7837caf62653d097ff85", // USDC
+                        "94b008aa00579c1307b0ef2c499ad98a8ce58e58", // USDT
+                    ]),
+                    whitelist_tokens: to_strings(&[
+                        "4200000000000000000000000000000000000006", // WETH
+                        "da10009cbd5d07dd0cecc66161fc93d7c9000da1", // DAI
+                        "7f5c764cbc14f9669b88837ca1490cca17c31607", // USDC.e
+                        "0b2c639c533813f4aa9d7837caf62653d097ff85", // USDC
+                        "94b008aa00579c1307b0ef2c499ad98a8ce58e58", // USDT
+                        "68f180fcce6836688e9084f035309e29bf0a2095", // WBTC
+                        "4200000000000000000000000000000000000042", // OP
+                    ]),
+                    minimum_native_locked: BigDecimal::from_str("10").unwrap(),
+                    max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+                    max_price_jump: None,
+                    quote_tokens: vec![],
+                },
+            },
+            "polygon" => NetworkConfig {
+                network: network.to_string(),
+                factory: "1F98431c8aD98523631AE4a59f267346ea31F984".to_string(),
+                position_manager: "c36442b4a4522e871399cd717abdd847ab11fe88".to_string(),
+                start_block: 22757547,
+                ignored_pools: vec![],
+                static_tokens: vec![],
+                seeded_pools: vec![],
+                pricing: PricingConfig {
+                    wrapped_native: "0d500b1d8e8ef31e21c99d1db9a6444d3adf1270".to_string(),
+                    reference_pools: vec![ReferencePool::new(
+                        "a374094527e1673a86de625aa59517c5de346d32", // WMATIC/USDC.e 0.05%
+                        "2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                    )],
+                    stable_coins: to_strings(&[
+                        "2791bca1f2de4661ed88a30c99a7a9449aa84174", // USDC.e
+                        "3c499c542cef5e3811e1192ce70d8cc03d5c3359", // USDC
+                        "c2132d05d31c914a87c6611c10748aeb04b58e8f", // USDT
+                        "8f3cf7ad23cd3cadbd9735aff958023239c6a063", // DAI
+                    ]),
+                    whitelist_tokens: to_strings(&[
+                        "0d500b1d8e8ef31e21c99d1db9a6444d3adf1270", // WMATIC
+                        "7ceb23fd6bc0add59e62ac25578270cff1b9f619", // WETH
+                        "2791bca1f2de4661ed88a30c99a7a9449aa84174", // USDC.e
+                        "3c499c542cef5e3811e1192ce70d8cc03d5c3359", // USDC
+                        "c2132d05d31c914a87c6611c10748aeb04b58e8f", // USDT
+                        "8f3cf7ad23cd3cadbd9735aff958023239c6a063", // DAI
+                        "1bfd67037b42cf73acf2047067bd4f2c47d9bfd6", // WBTC
+                    ]),
+                    minimum_native_locked: BigDecimal::from_str("20000").unwrap(),
+                    max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+                    max_price_jump: None,
+                    quote_tokens: vec![],
+                },
+            },
+            "base" => NetworkConfig {
+                network: network.to_string(),
+                factory: "33128a8fC17869897dcE68Ed026d694621f6FDfD".to_string(),
+                position_manager: "03a520b32c04bf3beef7beb72e919cf822ed34f1".to_string(),
+                start_block: 1371680,
+                ignored_pools: vec![],
+                static_tokens: vec![],
+                seeded_pools: vec![],
+                pricing: PricingConfig {
+                    wrapped_native: "4200000000000000000000000000000000000006".to_string(),
+                    reference_pools: vec![
+                        ReferencePool::new(
+                            "4c36388be6f416a29c8d8eee81c771ce6be14b18", // WETH/USDbC 0.05%
+                            "d9aaec86b65d86f6a7b5b1b0c42ffa531710b6ca",
+                        ),
+                        ReferencePool::new(
+                            "d0b53d9277642d899df5c87a3966a349a798f224", // WETH/USDC 0.05%
+                            "833589fcd6edb6e08f4c7c32d4f71b54bda02913",
+                        ),
+                    ],
+                    stable_coins: to_strings(&[
+                        "d9aaec86b65d86f6a7b5b1b0c42ffa531710b6ca", // USDbC
+                        "833589fcd6edb6e08f4c7c32d4f71b54bda02913", // USDC
+                        "50c5725949a6f0c72e6c4a641f24049a917db0cb", // DAI
+                    ]),
+                    whitelist_tokens: to_strings(&[
+                        "4200000000000000000000000000000000000006", // WETH
+                        "d9aaec86b65d86f6a7b5b1b0c42ffa531710b6ca", // USDbC
+                        "833589fcd6edb6e08f4c7c32d4f71b54bda02913", // USDC
+                        "50c5725949a6f0c72e6c4a641f24049a917db0cb", // DAI
+                    ]),
+                    minimum_native_locked: BigDecimal::from_str("1").unwrap(),
+                    max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+                    max_price_jump: None,
+                    quote_tokens: vec![],
+                },
+            },
+            "bsc" => NetworkConfig {
+                network: network.to_string(),
+                factory: "dB1d10011AD0Ff90774D0C6Bb92e5C5c8b4461F7".to_string(),
+                position_manager: "7b8a01b39d58278b5de7e48c8449c9f4f5170613".to_string(),
+                start_block: 26324014,
+                ignored_pools: vec![],
+                static_tokens: vec![],
+                seeded_pools: vec![],
+                pricing: PricingConfig {
+                    wrapped_native: "bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c".to_string(),
+                    reference_pools: vec![ReferencePool::new(
+                        "6fe9e9de56356f7edbfcbb29fab7cd69471a4869", // USDT/WBNB 0.05%
+                        "55d398326f99059ff775485246999027b3197955",
+                    )],
+                    stable_coins: to_strings(&[
+                        "55d398326f99059ff775485246999027b3197955", // USDT
+                        "8ac76a51cc950d9822d68b83fe1ad97b32cd580d", // USDC
+                        "e9e7cea3dedca5984780bafc599bd69add087d56", // BUSD
+                    ]),
+                    whitelist_tokens: to_strings(&[
+                        "bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c", // WBNB
+                        "55d398326f99059ff775485246999027b3197955", // USDT
+                        "8ac76a51cc950d9822d68b83fe1ad97b32cd580d", // USDC
+                        "e9e7cea3dedca5984780bafc599bd69add087d56", // BUSD
+                        "2170ed0880ac9a755fd29b2688956bd959f933f8", // ETH
+                        "7130d2a12b9bcbfae4f2634d864a1ee1ce3ead9c", // BTCB
+                    ]),
+                    minimum_native_locked: BigDecimal::from_str("100").unwrap(),
+                    max_pricing_hops: DEFAULT_MAX_PRICING_HOPS,
+                    max_price_jump: None,
+                    quote_tokens: vec![],
+                },
+            },
+            _ => panic!("unsupported network {network}"),
+        }
+    }
+
+    pub fn from_params(params: &String) -> NetworkConfig {
+        let network = split_params(params)
+            .find(|(key, _)| *key == "network")
+            .map_or("mainnet".to_string(), |(_, value)| value.trim().to_string());
+
+        let mut config = NetworkConfig::for_network(&network);
+        config.pricing.apply_params(params);
+        for (key, value) in split_params(params) {
+            match key {
+                "factory" => config.factory = value.trim().trim_start_matches("0x").to_string(),
+                "position_manager" => config.position_manager = address(value),
+                "static_tokens" => config.static_tokens = static_tokens(value),
+                "seeded_pools" => config.seeded_pools = addresses(value),
+                "start_block" => {
+                    config.start_block = value
+                        .trim()
+                        .parse::<u64>()
+                        .unwrap_or_else(|_| panic!("invalid start_block {value}"))
+                }
+                _ => {}
+            }
+        }
+        config
+    }
+
+    pub fn factory_id(&self) -> String {
+        format!("0x{}", self.factory)
+    }
+
+    pub fn factory_address(&self) -> Vec<u8> {
+        hex::decode(&self.factory).unwrap()
+    }
+
+    pub fn position_manager_address(&self) -> Vec<u8> {
+        hex::decode(&self.position_manager).unwrap()
+    }
+
+    pub fn is_ignored_pool(&self, pool_address: &str) -> bool {
+        self.ignored_pools.iter().any(|address| address == pool_address)
+    }
+
+    pub fn static_token(&self, token_address: &str) -> Option<&StaticToken> {
+        self.static_tokens.iter().find(|token| token.address == token_address)
+    }
+}
+
+/// Splits module params like `key=value&key=value` into their keys and values
+pub fn split_params(params: &str) -> impl Iterator<Item = (&str, &str)> {
+    params
+        .split("&")
+        .filter_map(|param| param.split_once("="))
+        .map(|(key, value)| (key.trim(), value))
+}
+
+fn to_strings(addresses: &[&str]) -> Vec<String> {
+    addresses.iter().map(|address| address.to_string()).collect()
+}
+
+// addresses are stored lowercase without the 0x prefix
+fn address(value: &str) -> String {
+    let value = value.trim().to_lowercase();
+    match value.strip_prefix("0x") {
+        Some(address) => address.to_string(),
+        None => value,
+    }
+}
+
+// pairs of pool and stable token addresses, ex: `0x<pool>:0x<stable token>,0x<pool>:0x<stable token>`
+fn reference_pools(value: &str) -> Vec<ReferencePool> {
+    value
+        .split(",")
+        .filter(|reference_pool| !reference_pool.trim().is_empty())
+        .map(|reference_pool| match reference_pool.split_once(":") {
+            Some((pool, stable_token)) => ReferencePool::new(&address(pool), &address(stable_token)),
+            None => panic!("invalid reference pool {reference_pool}, expected <pool>:<stable token>"),
+        })
+        .collect()
+}
+
+// tokens with their symbol, name and decimals, ex: `0x<token>:<symbol>:<name>:<decimals>,...`
+fn static_tokens(value: &str) -> Vec<StaticToken> {
+    value
+        .split(",")
+        .filter(|static_token| !static_token.trim().is_empty())
+        .map(
+            |static_token| match static_token.split(":").collect::<Vec<&str>>()[..] {
+                [token, symbol, name, decimals] => StaticToken::new(
+                    &address(token),
+                    symbol.trim(),
+                    name.trim(),
+                    decimals
+                        .trim()
+                        .parse::<u64>()
+                        .unwrap_or_else(|_| panic!("invalid static token decimals {decimals}")),
+                ),
+                _ => panic!("invalid static token {static_token}, expected <token>:<symbol>:<name>:<decimals>"),
+            },
+        )
+        .collect()
+}
+
+fn addresses(value: &str) -> Vec<String> {
+    value
+        .split(",")
+        .filter(|address| !address.trim().is_empty())
+        .map(address)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{NetworkConfig, PricingConfig, ReferencePool, StaticToken};
+    use std::str::FromStr;
+    use substreams::scalar::BigDecimal;
+
+    #[test]
+    fn defaults_without_params() {
+        assert_eq!(
+            PricingConfig::default(),
+            NetworkConfig::from_params(&"".to_string()).pricing
+        );
+    }
+
+    #[test]
+    fn override_from_params() {
+        let config = NetworkConfig::from_params(
+            &"wrapped_native=0x82aF49447D8a07e3bd95BD0d56f35241523fBab1&stable_coins=0xaa,0xBB&minimum_native_locked=10"
+                .to_string(),
+        )
+        .pricing;
+
+        assert_eq!("82af49447d8a07e3bd95bd0d56f35241523fbab1", config.wrapped_native);
+        assert_eq!(vec!["aa".to_string(), "bb".to_string()], config.stable_coins);
+        assert!(config.quote_tokens.is_empty());
+        assert_eq!(BigDecimal::from_str("10").unwrap(), config.minimum_native_locked);
+        assert!(config.is_stable_coin("bb"));
+        assert!(config.is_whitelisted("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+    }
+
+    #[test]
+    fn max_price_jump_from_params() {
+        let config = NetworkConfig::from_params(&"max_price_jump=2.5".to_string()).pricing;
+
+        assert_eq!(Some(BigDecimal::from_str("2.5").unwrap()), config.max_price_jump);
+    }
+
+    #[test]
+    fn quote_tokens_from_params() {
+        let config = NetworkConfig::from_params(
+            &"quote_tokens=0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599,0x1aBaEA1f7C830bD89Acc67eC4af516284b1bC33c"
+                .to_string(),
+        )
+        .pricing;
+
+        assert_eq!(
+            vec![
+                "2260fac5e5542a773aa44fbcfedf7c193bc2c599".to_string(),
+                "1abaea1f7c830bd89acc67ec4af516284b1bc33c".to_string()
+            ],
+            config.quote_tokens
+        );
+    }
+
+    #[test]
+    fn reference_pools_from_params() {
+        let config = NetworkConfig::from_params(&"reference_pools=0xAA:0x01, 0xbb:0x02".to_string()).pricing;
+
+        assert_eq!(
+            vec![ReferencePool::new("aa", "01"), ReferencePool::new("bb", "02")],
+            config.reference_pools
+        );
+    }
+
+    #[test]
+    fn network_from_params() {
+        assert_eq!(NetworkConfig::default(), NetworkConfig::from_params(&"".to_string()));
+
+        let config = NetworkConfig::from_params(
+            &"network=base&start_block=2000000&minimum_native_locked=5&max_pricing_hops=2".to_string(),
+        );
+        assert_eq!("base", config.network);
+        assert_eq!("0x33128a8fC17869897dcE68Ed026d694621f6FDfD", config.factory_id());
+        assert_eq!(2000000, config.start_block);
+        assert_eq!(
+            "4200000000000000000000000000000000000006",
+            config.pricing.wrapped_native
+        );
+        assert_eq!(BigDecimal::from_str("5").unwrap(), config.pricing.minimum_native_locked);
+        assert_eq!(2, config.pricing.max_pricing_hops);
+        assert_eq!(None, config.pricing.max_price_jump);
+    }
+
+    #[test]
+    fn static_tokens_of_the_network() {
+        let config = NetworkConfig::default();
+        assert_eq!(
+            Some(&StaticToken::new(
+                "e0b7927c4af23765cb51314a0e0521a9645f0e2a",
+                "DGD",
+                "DGD",
+                9
+            )),
+            config.static_token("e0b7927c4af23765cb51314a0e0521a9645f0e2a")
+        );
+        assert_eq!(None, config.static_token("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+
+        let config = NetworkConfig::from_params(&"network=optimism".to_string());
+        assert_eq!(None, config.static_token("e0b7927c4af23765cb51314a0e0521a9645f0e2a"));
+        assert_eq!(2, config.seeded_pools.len());
+    }
+
+    #[test]
+    fn static_tokens_and_seeded_pools_from_params() {
+        let config = NetworkConfig::from_params(
+            &"network=polygon&static_tokens=0xAA:AA:Token A:18, 0xbb:BB:Token B:6&seeded_pools=0xCC,0xdd".to_string(),
+        );
+        assert_eq!(
+            vec![
+                StaticToken::new("aa", "AA", "Token A", 18),
+                StaticToken::new("bb", "BB", "Token B", 6)
+            ],
+            config.static_tokens
+        );
+        assert_eq!(vec!["cc".to_string(), "dd".to_string()], config.seeded_pools);
+    }
+}
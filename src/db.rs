@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::ops::Div;
 use substreams::key::{
     key_first_segment_in, key_first_segments_in, key_last_segment_in, key_last_segments_in, operations_eq,
@@ -13,12 +14,15 @@ use substreams::store::{
 use substreams::{log, Hex};
 use substreams_entity_change::tables::Tables;
 
-use crate::pb::uniswap::events::pool_event::Type::{Burn as BurnEvent, Mint as MintEvent, Swap as SwapEvent};
+use crate::config::PricingConfig;
+use crate::pb::uniswap::events::pool_event::Type::{
+    Burn as BurnEvent, Collect as CollectEvent, Mint as MintEvent, Swap as SwapEvent,
+};
 use crate::pb::uniswap::events::position_event::Type;
 use crate::pb::uniswap::events::{IncreaseLiquidityPosition, PoolSqrtPrice, PositionEvent};
-use crate::pb::uniswap::{events, Events, Pool};
+use crate::pb::uniswap::{events, Events, FactoryEvents, Pool, PositionAmounts, PriceOutliers, TokenPrice};
 use crate::uniswap::{Erc20Token, Pools};
-use crate::{key, utils};
+use crate::{key, math, utils};
 
 // -------------------
 //  Map Bundle Entities
@@ -36,22 +40,41 @@ pub fn bundle_store_eth_price_usd_bundle_entity_change(
     for delta in derived_eth_prices_deltas
         .deltas
         .iter()
-        .filter(key_first_segment_in("bundle"))
+        .filter(|delta| delta.key == "bundle")
     {
         tables.update_row("Bundle", "1").set("ethPriceUSD", &delta.new_value);
     }
 }
 
+pub fn bundle_price_sources_entity_change(tables: &mut Tables, derived_eth_prices_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in derived_eth_prices_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("bundle"))
+    {
+        let pool_address = match key::try_segment(&delta.key, 1) {
+            None => continue,
+            Some(pool_address) => pool_address,
+        };
+
+        // sources without in-range liquidity are zeroed and left out of the median
+        tables
+            .update_row("BundlePriceSource", format!("0x{pool_address}"))
+            .set("bundle", "1")
+            .set("pool", format!("0x{pool_address}"))
+            .set("ethPriceUSD", &delta.new_value)
+            .set("included", delta.new_value.ne(&BigDecimal::zero()));
+    }
+}
+
 // -------------------
 //  Map Factory Entities
 // -------------------
-pub fn factory_created_factory_entity_change(tables: &mut Tables) {
-    let id = "0x1F98431c8aD98523631AE4a59f267346ea31F984";
-
+pub fn factory_created_factory_entity_change(tables: &mut Tables, factory_id: &String) {
     let bigint0 = BigInt::zero();
     let bigdecimal0 = BigDecimal::zero();
     tables
-        .create_row("Factory", id)
+        .create_row("Factory", factory_id)
         .set("poolCount", &bigint0)
         .set("txCount", &bigint0)
         .set("totalVolumeUSD", &bigdecimal0)
@@ -63,25 +86,45 @@ pub fn factory_created_factory_entity_change(tables: &mut Tables) {
         .set("totalValueLockedETH", &bigdecimal0)
         .set("totalValueLockedUSDUntracked", &bigdecimal0)
         .set("totalValueLockedETHUntracked", &bigdecimal0)
+        .set("protocolFeesAccruedUSD", &bigdecimal0)
+        .set("protocolFeesCollectedUSD", &bigdecimal0)
+        // overwritten by the `OwnerChanged` event the factory constructor emits in the same block
         .set("owner", &format!("0x{}", Hex(utils::ZERO_ADDRESS).to_string()));
 }
 
-pub fn pool_created_factory_entity_change(tables: &mut Tables, pool_count_deltas: &Deltas<DeltaBigInt>) {
+pub fn owner_changed_factory_entity_change(tables: &mut Tables, factory_id: &String, factory_events: &FactoryEvents) {
+    for owner_changed in &factory_events.owner_changes {
+        tables
+            .update_row("Factory", factory_id)
+            .set("owner", &format!("0x{}", owner_changed.new_owner));
+    }
+}
+
+pub fn pool_created_factory_entity_change(
+    tables: &mut Tables,
+    factory_id: &String,
+    pool_count_deltas: &Deltas<DeltaBigInt>,
+) {
     pool_count_deltas.deltas.iter().for_each(|delta| {
-        let id = "0x1F98431c8aD98523631AE4a59f267346ea31F984".to_string();
-        tables.update_row("Factory", &id).set("poolCount", &delta.new_value);
+        tables
+            .update_row("Factory", factory_id)
+            .set("poolCount", &delta.new_value);
     })
 }
 
-pub fn tx_count_factory_entity_change(tables: &mut Tables, tx_count_deltas: &Deltas<DeltaBigInt>) {
+pub fn tx_count_factory_entity_change(tables: &mut Tables, factory_id: &String, tx_count_deltas: &Deltas<DeltaBigInt>) {
     for delta in tx_count_deltas.deltas.iter().filter(key_first_segment_in("factory")) {
         tables
-            .update_row("Factory", "0x1F98431c8aD98523631AE4a59f267346ea31F984")
+            .update_row("Factory", factory_id)
             .set("txCount", &delta.new_value);
     }
 }
 
-pub fn swap_volume_factory_entity_change(tables: &mut Tables, swaps_volume_deltas: &Deltas<DeltaBigDecimal>) {
+pub fn swap_volume_factory_entity_change(
+    tables: &mut Tables,
+    factory_id: &String,
+    swaps_volume_deltas: &Deltas<DeltaBigDecimal>,
+) {
     for delta in swaps_volume_deltas
         .deltas
         .iter()
@@ -95,12 +138,16 @@ pub fn swap_volume_factory_entity_change(tables: &mut Tables, swaps_volume_delta
         ]))
     {
         tables
-            .update_row("Factory", "0x1F98431c8aD98523631AE4a59f267346ea31F984")
+            .update_row("Factory", factory_id)
             .set(key::last_segment(&delta.key), &delta.new_value);
     }
 }
 
-pub fn tvl_factory_entity_change(tables: &mut Tables, derived_factory_tvl_deltas: &Deltas<DeltaBigDecimal>) {
+pub fn tvl_factory_entity_change(
+    tables: &mut Tables,
+    factory_id: &String,
+    derived_factory_tvl_deltas: &Deltas<DeltaBigDecimal>,
+) {
     for delta in derived_factory_tvl_deltas
         .deltas
         .iter()
@@ -113,11 +160,50 @@ pub fn tvl_factory_entity_change(tables: &mut Tables, derived_factory_tvl_deltas
         ]))
     {
         tables
-            .update_row("Factory", "0x1F98431c8aD98523631AE4a59f267346ea31F984")
+            .update_row("Factory", factory_id)
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
+pub fn protocol_fees_factory_entity_change(
+    tables: &mut Tables,
+    factory_id: &String,
+    protocol_fees_deltas: &Deltas<DeltaBigDecimal>,
+) {
+    for delta in protocol_fees_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("factory"))
+        .filter(key_last_segments_in(vec![
+            "protocolFeesAccruedUSD",
+            "protocolFeesCollectedUSD",
+        ]))
+    {
+        tables
+            .update_row("Factory", factory_id)
             .set(key::last_segment(&delta.key), &delta.new_value);
     }
 }
 
+// -------------------
+//  Map FeeTier Entities
+// -------------------
+pub fn fee_amount_enabled_fee_tier_entity_change(
+    tables: &mut Tables,
+    factory_id: &String,
+    factory_events: &FactoryEvents,
+) {
+    for fee_amount_enabled in &factory_events.fee_amounts_enabled {
+        tables
+            .create_row("FeeTier", &fee_amount_enabled.fee)
+            .set("factory", factory_id)
+            .set_bigint("feeAmount", &fee_amount_enabled.fee)
+            .set("tickSpacing", BigInt::from(fee_amount_enabled.tick_spacing))
+            .set("createdAtTimestamp", BigInt::from(fee_amount_enabled.timestamp))
+            .set("createdAtBlockNumber", BigInt::from(fee_amount_enabled.block_number));
+    }
+}
+
 // -------------------
 //  Map Pool Entities
 // -------------------
@@ -145,6 +231,8 @@ fn create_pool(tables: &mut Tables, pool: &Pool) {
         .set("token1Price", &bigdecimal0)
         .set("tick", &bigint0)
         .set("observationIndex", &bigint0)
+        .set("observationCardinality", &bigint0)
+        .set("observationCardinalityNext", &bigint0)
         .set("volumeToken0", &bigdecimal0)
         .set("volumeToken1", &bigdecimal0)
         .set("volumeUSD", &bigdecimal0)
@@ -154,6 +242,14 @@ fn create_pool(tables: &mut Tables, pool: &Pool) {
         .set("collectedFeesToken0", &bigdecimal0)
         .set("collectedFeesToken1", &bigdecimal0)
         .set("collectedFeesUSD", &bigdecimal0)
+        .set("feeProtocol0", &bigint0)
+        .set("feeProtocol1", &bigint0)
+        .set("protocolFeesAccruedToken0", &bigdecimal0)
+        .set("protocolFeesAccruedToken1", &bigdecimal0)
+        .set("protocolFeesAccruedUSD", &bigdecimal0)
+        .set("protocolFeesCollectedToken0", &bigdecimal0)
+        .set("protocolFeesCollectedToken1", &bigdecimal0)
+        .set("protocolFeesCollectedUSD", &bigdecimal0)
         .set("totalValueLockedToken0", &bigdecimal0)
         .set("totalValueLockedToken1", &bigdecimal0)
         .set("totalValueLockedETH", &bigdecimal0)
@@ -233,6 +329,59 @@ pub fn liquidities_pool_entity_change(tables: &mut Tables, pool_liquidities_stor
     }
 }
 
+pub fn collected_fees_pool_entity_change(tables: &mut Tables, collected_fees_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in collected_fees_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("pool"))
+        .filter(key_last_segments_in(vec![
+            "collectedFeesToken0",
+            "collectedFeesToken1",
+            "collectedFeesUSD",
+        ]))
+    {
+        let pool_address = key::segment(&delta.key, 1);
+        tables
+            .update_row("Pool", &format!("0x{pool_address}"))
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
+pub fn fee_protocol_pool_entity_change(tables: &mut Tables, fee_protocol_deltas: &Deltas<DeltaBigInt>) {
+    for delta in fee_protocol_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("pool"))
+        .filter(key_last_segments_in(vec!["feeProtocol0", "feeProtocol1"]))
+    {
+        let pool_address = key::segment(&delta.key, 1);
+        tables
+            .update_row("Pool", &format!("0x{pool_address}"))
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
+pub fn protocol_fees_pool_entity_change(tables: &mut Tables, protocol_fees_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in protocol_fees_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("pool"))
+        .filter(key_last_segments_in(vec![
+            "protocolFeesAccruedToken0",
+            "protocolFeesAccruedToken1",
+            "protocolFeesAccruedUSD",
+            "protocolFeesCollectedToken0",
+            "protocolFeesCollectedToken1",
+            "protocolFeesCollectedUSD",
+        ]))
+    {
+        let pool_address = key::segment(&delta.key, 1);
+        tables
+            .update_row("Pool", &format!("0x{pool_address}"))
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
 pub fn fee_growth_global_pool_entity_change(tables: &mut Tables, updates: &Vec<events::FeeGrowthGlobal>) {
     for update in updates {
         let pool_address = &update.pool_address;
@@ -245,6 +394,22 @@ pub fn fee_growth_global_pool_entity_change(tables: &mut Tables, updates: &Vec<e
     }
 }
 
+pub fn oracle_pool_entity_change(tables: &mut Tables, updates: &Vec<events::PoolOracle>) {
+    for update in updates {
+        let pool_address = &update.pool_address;
+        let row = tables.update_row("Pool", &format!("0x{pool_address}"));
+        if let Some(observation_index) = update.observation_index {
+            row.set("observationIndex", BigInt::from(observation_index));
+        }
+        if let Some(observation_cardinality) = update.observation_cardinality {
+            row.set("observationCardinality", BigInt::from(observation_cardinality));
+        }
+        if let Some(observation_cardinality_next) = update.observation_cardinality_next {
+            row.set("observationCardinalityNext", BigInt::from(observation_cardinality_next));
+        }
+    }
+}
+
 pub fn total_value_locked_pool_entity_change(tables: &mut Tables, derived_tvl_deltas: &Deltas<DeltaBigDecimal>) {
     for delta in derived_tvl_deltas
         .deltas
@@ -388,6 +553,9 @@ fn add_token_entity_change(tables: &mut Tables, token: &Erc20Token) {
         .set("totalValueLockedUSD", &bigdecimal0)
         .set("totalValueLockedUSDUntracked", &bigdecimal0)
         .set("derivedETH", &bigdecimal0)
+        .set("derivedETHLocked", &bigdecimal0)
+        .set("derivedETHHops", 0)
+        .set("derivedETHUpdatedAtBlock", &bigint0)
         .set("whitelistPools", &whitelist);
 }
 
@@ -501,6 +669,110 @@ pub fn derived_eth_prices_token_entity_change(
     }
 }
 
+pub fn price_sources_token_entity_change(
+    tables: &mut Tables,
+    token_price_sources_deltas: &Deltas<DeltaProto<TokenPrice>>,
+) {
+    for delta in token_price_sources_deltas.deltas.iter() {
+        let token_price = &delta.new_value;
+        let row = tables
+            .update_row("Token", format!("0x{}", token_price.token_address))
+            .set_bigdecimal("derivedETHLocked", &token_price.eth_locked)
+            .set("derivedETHHops", token_price.hops as i32)
+            .set("derivedETHUpdatedAtBlock", BigInt::from(token_price.block_number));
+
+        if !token_price.source_pool.is_empty() {
+            row.set("derivedETHSourcePool", format!("0x{}", token_price.source_pool));
+        }
+    }
+}
+
+// The values in the quote tokens are derived from the ETH values at the latest ETH price of each quote token,
+// every time the token or pool is touched
+pub fn quote_entity_change(
+    tables: &mut Tables,
+    quote_tokens: &Vec<String>,
+    derived_eth_prices_deltas: &Deltas<DeltaBigDecimal>,
+    derived_tvl_deltas: &Deltas<DeltaBigDecimal>,
+    swaps_volume_deltas: &Deltas<DeltaBigDecimal>,
+    store_eth_prices: &StoreGetBigDecimal,
+    store_derived_tvl: &StoreGetBigDecimal,
+    store_swaps_volume: &StoreGetBigDecimal,
+) {
+    if quote_tokens.is_empty() {
+        return;
+    }
+
+    let mut tokens: HashSet<&str> = HashSet::new();
+    let mut pools: HashSet<&str> = HashSet::new();
+    for delta in derived_eth_prices_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("token"))
+        .filter(key_last_segment_in("eth"))
+    {
+        tokens.insert(key::segment(&delta.key, 1));
+    }
+    for delta in derived_tvl_deltas
+        .deltas
+        .iter()
+        .filter(key_last_segment_in("totalValueLockedETH"))
+    {
+        match key::first_segment(&delta.key) {
+            "token" => tokens.insert(key::segment(&delta.key, 1)),
+            "pool" => pools.insert(key::segment(&delta.key, 1)),
+            _ => continue,
+        };
+    }
+    for delta in swaps_volume_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("PoolQuote"))
+    {
+        pools.insert(key::segment(&delta.key, 1));
+    }
+
+    for quote_token in quote_tokens {
+        let quote_derived_eth = match store_eth_prices.get_last(format!("quote:{quote_token}")) {
+            None => continue,
+            Some(price) => price,
+        };
+        let in_quote =
+            |eth_value: Option<BigDecimal>| math::safe_div(&eth_value.unwrap_or_default(), &quote_derived_eth);
+
+        for token_address in tokens.iter() {
+            tables
+                .update_row("TokenQuote", format!("0x{token_address}#0x{quote_token}"))
+                .set("token", format!("0x{token_address}"))
+                .set("quote", format!("0x{quote_token}"))
+                .set(
+                    "derivedPrice",
+                    in_quote(store_eth_prices.get_last(format!("token:{token_address}:dprice:eth"))),
+                )
+                .set(
+                    "totalValueLocked",
+                    in_quote(store_derived_tvl.get_last(format!("token:{token_address}:totalValueLockedETH"))),
+                );
+        }
+        for pool_address in pools.iter() {
+            tables
+                .update_row("PoolQuote", format!("0x{pool_address}#0x{quote_token}"))
+                .set("pool", format!("0x{pool_address}"))
+                .set("quote", format!("0x{quote_token}"))
+                .set(
+                    "totalValueLocked",
+                    in_quote(store_derived_tvl.get_last(format!("pool:{pool_address}:totalValueLockedETH"))),
+                )
+                .set(
+                    "volume",
+                    store_swaps_volume
+                        .get_last(format!("PoolQuote:{pool_address}:{quote_token}:volume"))
+                        .unwrap_or_default(),
+                );
+        }
+    }
+}
+
 pub fn whitelist_token_entity_change(tables: &mut Tables, tokens_whitelist_pools_deltas: Deltas<DeltaArray<String>>) {
     for delta in tokens_whitelist_pools_deltas.deltas {
         let token_address = key::segment(&delta.key, 1);
@@ -516,24 +788,46 @@ pub fn whitelist_token_entity_change(tables: &mut Tables, tokens_whitelist_pools
 // --------------------
 //  Map Tick Entities
 // --------------------
-pub fn create_tick_entity_change(tables: &mut Tables, ticks_created: &Vec<events::TickCreated>) {
+pub fn create_tick_entity_change(
+    tables: &mut Tables,
+    ticks_created: &Vec<events::TickCreated>,
+    ticks_liquidities_deltas: &Deltas<DeltaBigInt>,
+) {
     let bigdecimal0 = BigDecimal::from(0);
     let bigint0 = BigInt::from(0);
 
+    // the ticks initialized for the first time are the ones whose liquidity keys are created by the block
+    let first_initialized: HashSet<&str> = ticks_liquidities_deltas
+        .deltas
+        .iter()
+        .filter(|delta| delta.operation == Operation::Create)
+        .map(|delta| delta.key.as_str())
+        .collect();
+
     for tick in ticks_created {
         let pool_address = &tick.pool_address;
         let tick_idx = &tick.idx;
         let id = format!("0x{pool_address}#{tick_idx}");
 
-        // We cannot determine when a new Tick is created. If a given tick idx was initialized
-        // in the past. In the future the same tick idx can be re-used for an event.
-        tables
+        // A tick is created each time it is initialized, a tick idx initialized in the past can be
+        // re-used once its liquidity went back to zero. The re-initialized ticks keep their volumes,
+        // fees and creation, only their liquidities and outside values start over.
+        let row = tables
             .update_row("Tick", &id)
-            .set("poolAddress", format!("0x{}", &tick.pool_address))
-            .set_bigint("tickIdx", &tick.idx)
-            .set("pool", &format!("0x{pool_address}"))
             .set("liquidityGross", &bigint0)
             .set("liquidityNet", &bigint0)
+            .set("feeGrowthOutside0X128", &bigint0)
+            .set("feeGrowthOutside1X128", &bigint0)
+            .set("tickCumulativeOutside", &bigint0)
+            .set("secondsPerLiquidityOutsideX128", &bigint0)
+            .set("secondsOutside", &bigint0);
+
+        if !first_initialized.contains(format!("tick:{pool_address}:{tick_idx}:liquidityGross").as_str()) {
+            continue;
+        }
+        row.set("poolAddress", format!("0x{}", &tick.pool_address))
+            .set_bigint("tickIdx", &tick.idx)
+            .set("pool", &format!("0x{pool_address}"))
             .set_bigdecimal("price0", &tick.price0)
             .set_bigdecimal("price1", &tick.price1)
             .set("volumeToken0", &bigdecimal0)
@@ -546,32 +840,111 @@ pub fn create_tick_entity_change(tables: &mut Tables, ticks_created: &Vec<events
             .set("collectedFeesUSD", &bigdecimal0)
             .set("createdAtTimestamp", tick.created_at_timestamp)
             .set("createdAtBlockNumber", tick.created_at_block_number)
-            .set("liquidityProviderCount", &bigint0)
-            .set("feeGrowthOutside0X128", &bigint0)
-            .set("feeGrowthOutside1X128", &bigint0);
+            .set("liquidityProviderCount", &bigint0);
     }
 }
 
-pub fn update_tick_entity_change(tables: &mut Tables, ticks_updated: &Vec<events::TickUpdated>) {
-    for tick in ticks_updated {
-        let pool_address = &tick.pool_address;
-        let tick_idx = &tick.idx;
+pub fn update_tick_entity_change(
+    tables: &mut Tables,
+    ticks_updated: &Vec<events::TickUpdated>,
+    ticks_crossed: &Vec<events::TickCrossed>,
+) {
+    for (pool_address, tick_idx, values) in tick_values(ticks_updated, ticks_crossed) {
         let row = tables.update_row("Tick", format!("0x{pool_address}#{tick_idx}"));
-        if tick.fee_growth_outside_0x_128.len() != 0 {
-            row.set_bigint("feeGrowthOutside0X128", &tick.fee_growth_outside_0x_128);
-        }
-        if tick.fee_growth_outside_1x_128.len() != 0 {
-            row.set_bigint("feeGrowthOutside1X128", &tick.fee_growth_outside_1x_128);
+        for (field_name, value) in values {
+            if value.len() != 0 {
+                row.set_bigint(field_name, value);
+            }
         }
     }
 }
 
-pub fn liquidities_tick_entity_change(tables: &mut Tables, ticks_liquidities_deltas: &Deltas<DeltaBigInt>) {
-    for delta in ticks_liquidities_deltas
+// The tick state decoded from the storage changes is authoritative: mints and burns update the liquidities and
+// fee growths outside of their ticks and swaps flip the outside values of the ticks they cross. They are merged
+// in ordinal order so the last value of the block wins, an empty value did not change.
+fn tick_values<'a>(
+    ticks_updated: &'a Vec<events::TickUpdated>,
+    ticks_crossed: &'a Vec<events::TickCrossed>,
+) -> Vec<(&'a String, &'a String, Vec<(&'static str, &'a String)>)> {
+    let mut updates: Vec<(u64, (&String, &String, Vec<(&str, &String)>))> = ticks_updated
+        .iter()
+        .map(|tick| {
+            (
+                tick.log_ordinal,
+                (
+                    &tick.pool_address,
+                    &tick.idx,
+                    vec![
+                        ("feeGrowthOutside0X128", &tick.fee_growth_outside_0x_128),
+                        ("feeGrowthOutside1X128", &tick.fee_growth_outside_1x_128),
+                        ("liquidityGross", &tick.liquidity_gross),
+                        ("liquidityNet", &tick.liquidity_net),
+                        ("tickCumulativeOutside", &tick.tick_cumulative_outside),
+                        (
+                            "secondsPerLiquidityOutsideX128",
+                            &tick.seconds_per_liquidity_outside_x128,
+                        ),
+                        ("secondsOutside", &tick.seconds_outside),
+                    ],
+                ),
+            )
+        })
+        .chain(ticks_crossed.iter().map(|tick| {
+            (
+                tick.log_ordinal,
+                (
+                    &tick.pool_address,
+                    &tick.idx,
+                    vec![
+                        ("feeGrowthOutside0X128", &tick.fee_growth_outside_0x_128),
+                        ("feeGrowthOutside1X128", &tick.fee_growth_outside_1x_128),
+                        ("tickCumulativeOutside", &tick.tick_cumulative_outside),
+                        (
+                            "secondsPerLiquidityOutsideX128",
+                            &tick.seconds_per_liquidity_outside_x128,
+                        ),
+                        ("secondsOutside", &tick.seconds_outside),
+                    ],
+                ),
+            )
+        }))
+        .collect();
+    updates.sort_by_key(|(log_ordinal, _)| *log_ordinal);
+    updates.into_iter().map(|(_, update)| update).collect()
+}
+
+pub fn swap_volume_tick_entity_change(tables: &mut Tables, swaps_volume_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in swaps_volume_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("tick"))
+        .filter(key_last_segments_in(vec![
+            "volumeToken0",
+            "volumeToken1",
+            "volumeUSD",
+            "untrackedVolumeUSD",
+            "feesUSD",
+        ]))
+    {
+        let pool_id = key::segment(&delta.key, 1);
+        let tick_idx = key::segment(&delta.key, 2);
+
+        tables
+            .update_row("Tick", &format!("0x{pool_id}#{tick_idx}"))
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
+pub fn collected_fees_tick_entity_change(tables: &mut Tables, collected_fees_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in collected_fees_deltas
         .deltas
         .iter()
         .filter(key_first_segment_in("tick"))
-        .filter(key_last_segments_in(vec!["liquidityNet", "liquidityGross"]))
+        .filter(key_last_segments_in(vec![
+            "collectedFeesToken0",
+            "collectedFeesToken1",
+            "collectedFeesUSD",
+        ]))
     {
         let pool_id = key::segment(&delta.key, 1);
         let tick_idx = key::segment(&delta.key, 2);
@@ -585,104 +958,127 @@ pub fn liquidities_tick_entity_change(tables: &mut Tables, ticks_liquidities_del
 // -----------------------
 //  Map Tick Day/Hour data
 // -----------------------
-// pub fn create_entity_tick_windows(tables: &mut Tables, ticks_created: &Vec<events::TickCreated>) {
-//     for tick in ticks_created {
-//         let day_id = tick.created_at_timestamp / 86400;
-//         let hour_id = tick.created_at_timestamp / 3600;
-//
-//         log::info!("create_entity_tick_windows hour_id {}", hour_id);
-//
-//         let pool_address = &tick.pool_address;
-//         let tick_idx = &tick.idx;
-//         create_tick_windows(tables, "TickDayData", pool_address.as_str(), tick_idx, day_id);
-//         // create_tick_windows(tables, "TickHourData", pool_address.as_str(), tick_idx, hour_id);
-//     }
-// }
-//
-// pub fn update_tick_windows(tables: &mut Tables, ticks_updated: &Vec<events::TickUpdated>) {
-//     for tick in ticks_updated {
-//         let day_id = tick.timestamp / 86400;
-//         let hour_id = tick.timestamp / 3600;
-//
-//         log::info!("update_tick_windows hour_id {}", hour_id);
-//
-//         let tick_idx = &tick.idx;
-//         let pool_address = &tick.pool_address;
-//
-//         if tick.fee_growth_outside_0x_128.len() != 0 {
-//             tables
-//                 .update_row("TickDayData", format!("0x{pool_address}#{tick_idx}-{day_id}"))
-//                 .set_bigint("feeGrowthOutside0X128", &tick.fee_growth_outside_0x_128);
-//             // tables
-//             //     .update_row("TickHourData", format!("0x{pool_address}#{tick_idx}-{hour_id}"))
-//             //     .set_bigint("feeGrowthOutside0X128", &tick.fee_growth_outside_0x_128);
-//         }
-//         if tick.fee_growth_outside_1x_128.len() != 0 {
-//             tables
-//                 .update_row("TickDayData", format!("0x{pool_address}#{tick_idx}-{day_id}"))
-//                 .set_bigint("feeGrowthOutside1X128", &tick.fee_growth_outside_1x_128);
-//             // tables
-//             //     .update_row("TickHourData", format!("0x{pool_address}#{tick_idx}-{hour_id}"))
-//             //     .set_bigint("feeGrowthOutside1X128", &tick.fee_growth_outside_1x_128);
-//         }
-//     }
-// }
-//
-// pub fn liquidities_tick_windows(tables: &mut Tables, ticks_liquidities_deltas: &Deltas<DeltaBigInt>) {
-//     for delta in ticks_liquidities_deltas.deltas.iter() {
-//         let table_name = match key::first_segment(&delta.key) {
-//             "TickDayData" => "TickDayData",
-//             // "TickHourData" => "TickHourData",
-//             _ => continue,
-//         };
-//         let time_id = key::segment(&delta.key, 1);
-//
-//         log::info!("liquidities_tick_windows time_id {}", time_id);
-//
-//         let pool_address = key::segment(&delta.key, 2);
-//         let tick_idx = key::segment(&delta.key, 3);
-//
-//         let field_name = match key::last_segment(&delta.key) {
-//             "liquidityNet" => "liquidityNet",
-//             "liquidityGross" => "liquidityGross",
-//             _ => continue,
-//         };
-//
-//         tables
-//             .update_row(table_name, format!("0x{pool_address}#{tick_idx}-{time_id}"))
-//             .set(field_name, &delta.new_value);
-//     }
-// }
-//
-// fn create_tick_windows(tables: &mut Tables, table_name: &str, pool_address: &str, tick_idx: &String, time_id: u64) {
-//     let bigdecimal0 = BigDecimal::from(0);
-//     let bigint0 = BigInt::from(0);
-//
-//     // We cannot determine when a new Tick is created. If a given tick idx was initialized
-//     // in the past. In the future the same tick idx can be re-used for an event.
-//     let row = tables
-//         .update_row(table_name, format!("0x{pool_address}#{tick_idx}-{time_id}"))
-//         .set("pool", &format!("0x{pool_address}"))
-//         .set("tick", &format!("0x{pool_address}#{tick_idx}"))
-//         .set("liquidityGross", &bigint0)
-//         .set("liquidityNet", &bigint0)
-//         .set("volumeToken0", &bigdecimal0)
-//         .set("volumeToken1", &bigdecimal0)
-//         .set("volumeUSD", &bigdecimal0)
-//         .set("feesUSD", &bigdecimal0);
-//
-//     match table_name {
-//         "TickDayData" => {
-//             row.set("date", (time_id * 86400) as i32);
-//             row.set("feeGrowthOutside0X128", &bigint0);
-//             row.set("feeGrowthOutside1X128", &bigint0);
-//         }
-//         "TickHourData" => {
-//             row.set("periodStartUnix", (time_id * 3600) as i32);
-//         }
-//         _ => {}
-//     }
-// }
+// Every tick touched in the block gets its day and hour windows rewritten from the latest store values,
+// so the first write of a window is complete and the following ones are idempotent.
+pub fn tick_windows_entity_change(
+    tables: &mut Tables,
+    timestamp: i64,
+    ticks_updated: &Vec<events::TickUpdated>,
+    ticks_crossed: &Vec<events::TickCrossed>,
+    ticks_liquidities_deltas: &Deltas<DeltaBigInt>,
+    swaps_volume_deltas: &Deltas<DeltaBigDecimal>,
+    store_ticks_liquidities: &StoreGetBigInt,
+    store_swaps_volume: &StoreGetBigDecimal,
+    store_fee_growth: &StoreGetBigInt,
+) {
+    let day_id = timestamp / 86400;
+    let hour_id = timestamp / 3600;
+
+    let mut ticks: Vec<(String, String)> = vec![];
+    let touched_keys = ticks_liquidities_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("tick"))
+        .map(|delta| &delta.key)
+        .chain(
+            swaps_volume_deltas
+                .deltas
+                .iter()
+                .filter(key_first_segment_in("tick"))
+                .map(|delta| &delta.key),
+        );
+    for key in touched_keys {
+        let tick = (key::segment(key, 1).to_string(), key::segment(key, 2).to_string());
+        if !ticks.contains(&tick) {
+            ticks.push(tick);
+        }
+    }
+    for (pool_address, tick_idx, _) in tick_values(ticks_updated, ticks_crossed) {
+        let tick = (pool_address.clone(), tick_idx.clone());
+        if !ticks.contains(&tick) {
+            ticks.push(tick);
+        }
+    }
+
+    for (pool_address, tick_idx) in &ticks {
+        for (table_name, time_id) in [("TickDayData", day_id), ("TickHourData", hour_id)] {
+            upsert_tick_windows_entity(
+                tables,
+                table_name,
+                time_id,
+                pool_address,
+                tick_idx,
+                store_ticks_liquidities,
+                store_swaps_volume,
+                store_fee_growth,
+            );
+        }
+    }
+
+    // the decoded liquidities replace the ones of the store
+    let window_fields = ["liquidityGross", "liquidityNet"];
+    for (pool_address, tick_idx, values) in tick_values(ticks_updated, ticks_crossed) {
+        for (table_name, time_id) in [("TickDayData", day_id), ("TickHourData", hour_id)] {
+            let row = tables.update_row(table_name, format!("0x{pool_address}#{tick_idx}-{time_id}"));
+            for (field_name, value) in values.iter() {
+                if window_fields.contains(field_name) && value.len() != 0 {
+                    row.set_bigint(*field_name, *value);
+                }
+            }
+        }
+    }
+}
+
+fn upsert_tick_windows_entity(
+    tables: &mut Tables,
+    table_name: &str,
+    time_id: i64,
+    pool_address: &str,
+    tick_idx: &str,
+    store_ticks_liquidities: &StoreGetBigInt,
+    store_swaps_volume: &StoreGetBigDecimal,
+    store_fee_growth: &StoreGetBigInt,
+) {
+    let liquidity = |field: &str| {
+        store_ticks_liquidities
+            .get_last(format!("tick:{pool_address}:{tick_idx}:{field}"))
+            .unwrap_or(BigInt::zero())
+    };
+    let volume = |field: &str| {
+        store_swaps_volume
+            .get_last(format!("{table_name}:{time_id}:{pool_address}:{tick_idx}:{field}"))
+            .unwrap_or(BigDecimal::zero())
+    };
+    // the fee growth outside is only known when it changed, the windows carry its last known value
+    let fee_growth = |field: &str| {
+        store_fee_growth
+            .get_last(format!("tick:{pool_address}:{tick_idx}:{field}"))
+            .unwrap_or(BigInt::zero())
+    };
+
+    let row = tables
+        .update_row(table_name, format!("0x{pool_address}#{tick_idx}-{time_id}"))
+        .set("pool", format!("0x{pool_address}"))
+        .set("tick", format!("0x{pool_address}#{tick_idx}"))
+        .set("liquidityGross", liquidity("liquidityGross"))
+        .set("liquidityNet", liquidity("liquidityNet"))
+        .set("volumeToken0", volume("volumeToken0"))
+        .set("volumeToken1", volume("volumeToken1"))
+        .set("volumeUSD", volume("volumeUSD"))
+        .set("feesUSD", volume("feesUSD"))
+        .set("feeGrowthOutside0X128", fee_growth("feeGrowthOutside0X128"))
+        .set("feeGrowthOutside1X128", fee_growth("feeGrowthOutside1X128"));
+
+    match table_name {
+        "TickDayData" => {
+            row.set("date", (time_id * 86400) as i32);
+        }
+        "TickHourData" => {
+            row.set("periodStartUnix", (time_id * 3600) as i32);
+        }
+        _ => {}
+    }
+}
 
 // --------------------
 //  Map Position Entities
@@ -705,6 +1101,14 @@ pub fn position_create_entity_change(tables: &mut Tables, positions: &Vec<events
             .set("withdrawnToken1", &bigdecimal0)
             .set("collectedFeesToken0", &bigdecimal0)
             .set("collectedFeesToken1", &bigdecimal0)
+            .set("amount0", &bigdecimal0)
+            .set("amount1", &bigdecimal0)
+            .set("amountUSD", &bigdecimal0)
+            .set("uncollectedFeesToken0", &bigdecimal0)
+            .set("uncollectedFeesToken1", &bigdecimal0)
+            .set("uncollectedFeesUSD", &bigdecimal0)
+            .set_bigint("tokensOwed0", &"0".to_string())
+            .set_bigint("tokensOwed1", &"0".to_string())
             .set("transaction", format!("0x{}", position.transaction))
             .set_bigint(
                 "feeGrowthInside0LastX128",
@@ -737,6 +1141,18 @@ pub fn increase_liquidity_position_entity_change(tables: &mut Tables, positions:
                 .update_row("Position", token_id)
                 .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
         }
+
+        if let Some(tokens_owed0) = &position.tokens_owed0 {
+            tables
+                .update_row("Position", token_id)
+                .set_bigint("tokensOwed0", tokens_owed0);
+        }
+
+        if let Some(tokens_owed1) = &position.tokens_owed1 {
+            tables
+                .update_row("Position", token_id)
+                .set_bigint("tokensOwed1", tokens_owed1);
+        }
     }
 }
 
@@ -763,18 +1179,24 @@ pub fn decrease_liquidity_position_entity_change(
                 .update_row("Position", &token_id)
                 .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
         }
+
+        if let Some(tokens_owed0) = &position.tokens_owed0 {
+            tables
+                .update_row("Position", &token_id)
+                .set_bigint("tokensOwed0", tokens_owed0);
+        }
+
+        if let Some(tokens_owed1) = &position.tokens_owed1 {
+            tables
+                .update_row("Position", &token_id)
+                .set_bigint("tokensOwed1", tokens_owed1);
+        }
     }
 }
 
 pub fn collect_position_entity_change(tables: &mut Tables, positions: &Vec<events::CollectPosition>) {
     for position in positions {
         let token_id = position.token_id.clone();
-        log::info!("collected_fees_token0 {}", position.collected_fees_token0);
-        log::info!("collected_fees_token1 {}", position.collected_fees_token1);
-        tables
-            .update_row("Position", &token_id)
-            .set_bigdecimal("collectedFeesToken0", &position.collected_fees_token0)
-            .set_bigdecimal("collectedFeesToken1", &position.collected_fees_token1);
 
         if let Some(fee_growth_inside0_last_x128) = &position.fee_growth_inside0_last_x128 {
             tables
@@ -787,6 +1209,45 @@ pub fn collect_position_entity_change(tables: &mut Tables, positions: &Vec<event
                 .update_row("Position", &token_id)
                 .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
         }
+
+        if let Some(tokens_owed0) = &position.tokens_owed0 {
+            tables
+                .update_row("Position", &token_id)
+                .set_bigint("tokensOwed0", tokens_owed0);
+        }
+
+        if let Some(tokens_owed1) = &position.tokens_owed1 {
+            tables
+                .update_row("Position", &token_id)
+                .set_bigint("tokensOwed1", tokens_owed1);
+        }
+    }
+}
+
+pub fn collected_fees_position_entity_change(tables: &mut Tables, collected_fees_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in collected_fees_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("position"))
+        .filter(key_last_segments_in(vec!["collectedFeesToken0", "collectedFeesToken1"]))
+    {
+        let token_id = key::segment(&delta.key, 1);
+        tables
+            .update_row("Position", token_id)
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
+}
+
+pub fn amounts_position_entity_change(tables: &mut Tables, position_amounts: &PositionAmounts) {
+    for position_amount in position_amounts.amounts.iter() {
+        tables
+            .update_row("Position", &position_amount.position)
+            .set_bigdecimal("amount0", &position_amount.amount0)
+            .set_bigdecimal("amount1", &position_amount.amount1)
+            .set_bigdecimal("amountUSD", &position_amount.amount_usd)
+            .set_bigdecimal("uncollectedFeesToken0", &position_amount.uncollected_fees_token0)
+            .set_bigdecimal("uncollectedFeesToken1", &position_amount.uncollected_fees_token1)
+            .set_bigdecimal("uncollectedFeesUSD", &position_amount.uncollected_fees_usd);
     }
 }
 
@@ -823,6 +1284,11 @@ fn create_snapshot_position(tables: &mut Tables, id: &String, position: &events:
         .set_bigdecimal("withdrawnToken1", &"0".to_string())
         .set_bigdecimal("collectedFeesToken0", &"0".to_string())
         .set_bigdecimal("collectedFeesToken1", &"0".to_string())
+        .set_bigdecimal("uncollectedFeesToken0", &"0".to_string())
+        .set_bigdecimal("uncollectedFeesToken1", &"0".to_string())
+        .set_bigdecimal("uncollectedFeesUSD", &"0".to_string())
+        .set_bigint("tokensOwed0", &"0".to_string())
+        .set_bigint("tokensOwed1", &"0".to_string())
         .set("transaction", &format!("0x{}", &position.transaction))
         .set_bigint(
             "feeGrowthInside0LastX128",
@@ -839,10 +1305,11 @@ pub fn increase_liquidity_snapshot_position_entity_change(
     block_number: u64,
     positions: &Vec<IncreaseLiquidityPosition>,
     store_positions: &StoreGetProto<PositionEvent>,
+    store_collected_fees: &StoreGetBigDecimal,
 ) {
     for position in positions {
         let id = format!("{}#{}", position.token_id, block_number);
-        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions);
+        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions, &store_collected_fees);
         increase_liquidity_snapshot_position(tables, &id, &position)
     }
 }
@@ -865,6 +1332,18 @@ fn increase_liquidity_snapshot_position(tables: &mut Tables, id: &String, positi
             .update_row("PositionSnapshot", &id)
             .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
     }
+
+    if let Some(tokens_owed0) = &position.tokens_owed0 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed0", tokens_owed0);
+    }
+
+    if let Some(tokens_owed1) = &position.tokens_owed1 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed1", tokens_owed1);
+    }
 }
 
 pub fn decrease_liquidity_snapshot_position_entity_change(
@@ -872,10 +1351,11 @@ pub fn decrease_liquidity_snapshot_position_entity_change(
     block_number: u64,
     positions: &Vec<events::DecreaseLiquidityPosition>,
     store_positions: &StoreGetProto<PositionEvent>,
+    store_collected_fees: &StoreGetBigDecimal,
 ) {
     for position in positions {
         let id = format!("{}#{}", position.token_id, block_number);
-        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions);
+        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions, &store_collected_fees);
         decrease_liquidity_snapshot_position(tables, &id, &position)
     }
 }
@@ -902,6 +1382,18 @@ fn decrease_liquidity_snapshot_position(
             .update_row("PositionSnapshot", &id)
             .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
     }
+
+    if let Some(tokens_owed0) = &position.tokens_owed0 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed0", tokens_owed0);
+    }
+
+    if let Some(tokens_owed1) = &position.tokens_owed1 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed1", tokens_owed1);
+    }
 }
 
 pub fn collect_snapshot_position_entity_change(
@@ -909,20 +1401,16 @@ pub fn collect_snapshot_position_entity_change(
     block_number: u64,
     positions: &Vec<events::CollectPosition>,
     store_positions: &StoreGetProto<PositionEvent>,
+    store_collected_fees: &StoreGetBigDecimal,
 ) {
     for position in positions {
         let id = format!("{}#{}", position.token_id, block_number);
-        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions);
+        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions, &store_collected_fees);
         collection_snapshot_position(tables, &id, &position);
     }
 }
 
 fn collection_snapshot_position(tables: &mut Tables, id: &String, position: &events::CollectPosition) {
-    tables
-        .update_row("PositionSnapshot", &id)
-        .set_bigdecimal("collectedFeesToken0", &position.collected_fees_token0)
-        .set_bigdecimal("collectedFeesToken1", &position.collected_fees_token1);
-
     if let Some(fee_growth_inside0_last_x128) = &position.fee_growth_inside0_last_x128 {
         tables
             .update_row("PositionSnapshot", &id)
@@ -934,6 +1422,18 @@ fn collection_snapshot_position(tables: &mut Tables, id: &String, position: &eve
             .update_row("PositionSnapshot", &id)
             .set_bigint("feeGrowthInside1LastX128", fee_growth_inside1_last_x128);
     }
+
+    if let Some(tokens_owed0) = &position.tokens_owed0 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed0", tokens_owed0);
+    }
+
+    if let Some(tokens_owed1) = &position.tokens_owed1 {
+        tables
+            .update_row("PositionSnapshot", &id)
+            .set_bigint("tokensOwed1", tokens_owed1);
+    }
 }
 
 pub fn transfer_snapshot_position_entity_change(
@@ -941,10 +1441,11 @@ pub fn transfer_snapshot_position_entity_change(
     block_number: u64,
     positions: &Vec<events::TransferPosition>,
     store_positions: &StoreGetProto<PositionEvent>,
+    store_collected_fees: &StoreGetBigDecimal,
 ) {
     for position in positions {
         let id = format!("{}#{}", position.token_id, block_number);
-        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions);
+        fetch_and_update_snapshot_position(tables, &position.token_id, &id, &store_positions, &store_collected_fees);
         transfer_snapshot_position(tables, &id, &position);
     }
 }
@@ -960,6 +1461,7 @@ fn fetch_and_update_snapshot_position(
     token_id: &String,
     snapshot_id: &String,
     store_positions: &StoreGetProto<PositionEvent>,
+    store_collected_fees: &StoreGetBigDecimal,
 ) {
     if let Some(position) = store_positions.get_last(format!("position_created:{}", token_id)) {
         match position.r#type.unwrap() {
@@ -999,6 +1501,156 @@ fn fetch_and_update_snapshot_position(
             _ => {}
         }
     }
+
+    // the snapshot carries the all time collected fees of the position at the end of the block
+    for field_name in ["collectedFeesToken0", "collectedFeesToken1"] {
+        if let Some(collected_fees) = store_collected_fees.get_last(format!("position:{token_id}:{field_name}")) {
+            tables
+                .update_row("PositionSnapshot", snapshot_id)
+                .set(field_name, &collected_fees);
+        }
+    }
+}
+
+// the snapshots of the block record the uncollected fees of their position at the end of the block
+pub fn uncollected_fees_snapshot_position_entity_change(
+    tables: &mut Tables,
+    block_number: u64,
+    events: &Events,
+    position_amounts: &PositionAmounts,
+) {
+    let snapshot_token_ids: HashSet<&String> = events
+        .created_positions
+        .iter()
+        .map(|position| &position.token_id)
+        .chain(
+            events
+                .increase_liquidity_positions
+                .iter()
+                .map(|position| &position.token_id),
+        )
+        .chain(
+            events
+                .decrease_liquidity_positions
+                .iter()
+                .map(|position| &position.token_id),
+        )
+        .chain(events.collect_positions.iter().map(|position| &position.token_id))
+        .chain(events.transfer_positions.iter().map(|position| &position.token_id))
+        .collect();
+
+    for position_amount in position_amounts.amounts.iter() {
+        if !snapshot_token_ids.contains(&position_amount.position) {
+            continue;
+        }
+
+        tables
+            .update_row(
+                "PositionSnapshot",
+                format!("{}#{}", position_amount.position, block_number),
+            )
+            .set_bigdecimal("uncollectedFeesToken0", &position_amount.uncollected_fees_token0)
+            .set_bigdecimal("uncollectedFeesToken1", &position_amount.uncollected_fees_token1)
+            .set_bigdecimal("uncollectedFeesUSD", &position_amount.uncollected_fees_usd);
+    }
+}
+
+// --------------------
+//  Map Raw Position Entities
+// --------------------
+pub fn raw_position_create_entity_change(
+    tables: &mut Tables,
+    raw_position_updates: &Vec<events::RawPositionUpdate>,
+    raw_position_amounts_deltas: &Deltas<DeltaBigDecimal>,
+) {
+    // the amounts of a raw position are all added on its first event, only then are its keys created
+    let created_ids: HashSet<&str> = raw_position_amounts_deltas
+        .deltas
+        .iter()
+        .filter(key_last_segment_in("depositedToken0"))
+        .filter(operations_eq(Operation::Create))
+        .map(|delta| key::segment(&delta.key, 1))
+        .collect();
+
+    let bigdecimal0 = BigDecimal::from(0);
+    let mut created: HashSet<String> = HashSet::new();
+    for position in raw_position_updates {
+        let id = position.id();
+        if !created_ids.contains(id.as_str()) || !created.insert(id.clone()) {
+            continue;
+        }
+
+        let pool_address = &position.pool_address;
+        tables
+            .create_row("RawPosition", &id)
+            .set("owner", &hex::decode(&position.owner).unwrap())
+            .set("pool", format!("0x{pool_address}"))
+            .set("token0", format!("0x{}", position.token0))
+            .set("token1", format!("0x{}", position.token1))
+            .set("tickLower", format!("0x{pool_address}#{}", &position.tick_lower))
+            .set("tickUpper", format!("0x{pool_address}#{}", &position.tick_upper))
+            .set_bigint("liquidity", &"0".to_string())
+            .set("depositedToken0", &bigdecimal0)
+            .set("depositedToken1", &bigdecimal0)
+            .set("withdrawnToken0", &bigdecimal0)
+            .set("withdrawnToken1", &bigdecimal0)
+            .set("collectedToken0", &bigdecimal0)
+            .set("collectedToken1", &bigdecimal0)
+            .set_bigint("tokensOwed0", &"0".to_string())
+            .set_bigint("tokensOwed1", &"0".to_string())
+            .set_bigint("feeGrowthInside0LastX128", &"0".to_string())
+            .set_bigint("feeGrowthInside1LastX128", &"0".to_string())
+            .set("createdAtTimestamp", position.timestamp)
+            .set("createdAtBlockNumber", position.block_number)
+            .set("transaction", format!("0x{}", position.transaction_id));
+    }
+}
+
+pub fn raw_position_update_entity_change(tables: &mut Tables, raw_position_updates: &Vec<events::RawPositionUpdate>) {
+    let mut raw_position_updates: Vec<&events::RawPositionUpdate> = raw_position_updates.iter().collect();
+    raw_position_updates.sort_by(|x, y| x.log_ordinal.cmp(&y.log_ordinal));
+
+    for position in raw_position_updates {
+        let row = tables.update_row("RawPosition", position.id());
+        // the position values are only known when they changed
+        for (field_name, value) in [
+            ("feeGrowthInside0LastX128", &position.fee_growth_inside0_last_x128),
+            ("feeGrowthInside1LastX128", &position.fee_growth_inside1_last_x128),
+            ("tokensOwed0", &position.tokens_owed0),
+            ("tokensOwed1", &position.tokens_owed1),
+        ] {
+            if let Some(value) = value {
+                row.set_bigint(field_name, value);
+            }
+        }
+    }
+}
+
+pub fn liquidities_raw_position_entity_change(
+    tables: &mut Tables,
+    raw_position_liquidities_deltas: &Deltas<DeltaBigInt>,
+) {
+    for delta in raw_position_liquidities_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("raw_position"))
+    {
+        tables
+            .update_row("RawPosition", key::segment(&delta.key, 1))
+            .set("liquidity", &delta.new_value);
+    }
+}
+
+pub fn amounts_raw_position_entity_change(tables: &mut Tables, raw_position_amounts_deltas: &Deltas<DeltaBigDecimal>) {
+    for delta in raw_position_amounts_deltas
+        .deltas
+        .iter()
+        .filter(key_first_segment_in("raw_position"))
+    {
+        tables
+            .update_row("RawPosition", key::segment(&delta.key, 1))
+            .set(key::last_segment(&delta.key), &delta.new_value);
+    }
 }
 
 // --------------------
@@ -1022,8 +1674,10 @@ pub fn transaction_entity_change(tables: &mut Tables, transactions: &Vec<events:
 pub fn swaps_mints_burns_created_entity_change(
     tables: &mut Tables,
     pool_events: &Vec<events::PoolEvent>,
-    tx_count_store: StoreGetBigInt,
-    store_eth_prices: StoreGetBigDecimal,
+    tx_count_store: &StoreGetBigInt,
+    store_eth_prices: &StoreGetBigDecimal,
+    price_outliers: &PriceOutliers,
+    config: &PricingConfig,
 ) {
     for pool_event in pool_events {
         if pool_event.r#type.is_none() {
@@ -1071,9 +1725,16 @@ pub fn swaps_mints_burns_created_entity_change(
                         &amount0_abs,
                         &amount1_abs,
                         &bundle_eth_price, // get the value from the store_eth_price
+                        config,
                     )
                     .div(BigDecimal::from(2 as i32));
 
+                    // the derived prices were not updated by this swap, see `map_price_outliers`
+                    let price_outlier = price_outliers
+                        .outliers
+                        .iter()
+                        .any(|outlier| outlier.ordinal == ord && &outlier.pool_address == pool_address);
+
                     tables
                         .create_row("Swap", &event_primary_key)
                         .set("transaction", format!("0x{transaction_id}"))
@@ -1089,7 +1750,8 @@ pub fn swaps_mints_burns_created_entity_change(
                         .set("amountUSD", &amount_total_usd_tracked)
                         .set("sqrtPriceX96", &BigInt::try_from(swap.sqrt_price.to_string()).unwrap())
                         .set("tick", &BigInt::try_from(swap.tick.to_string()).unwrap())
-                        .set("logIndex", pool_event.log_index);
+                        .set("logIndex", pool_event.log_index)
+                        .set("priceOutlier", price_outlier);
                 }
                 MintEvent(mint) => {
                     let amount0 = BigDecimal::try_from(mint.amount_0.as_str()).unwrap();
@@ -1149,6 +1811,30 @@ pub fn swaps_mints_burns_created_entity_change(
                         .set_bigint("tickUpper", &burn.tick_upper)
                         .set("logIndex", pool_event.log_index);
                 }
+                CollectEvent(collect) => {
+                    let amount0: BigDecimal = BigDecimal::try_from(collect.amount_0.as_str()).unwrap();
+                    let amount1: BigDecimal = BigDecimal::try_from(collect.amount_1.as_str()).unwrap();
+
+                    let amount_usd: BigDecimal = utils::calculate_amount_usd(
+                        &amount0,
+                        &amount1,
+                        &token0_derived_eth_price,
+                        &token1_derived_eth_price,
+                        &bundle_eth_price,
+                    );
+                    tables
+                        .create_row("Collect", &event_primary_key)
+                        .set("transaction", format!("0x{transaction_id}"))
+                        .set("pool", format!("0x{pool_address}"))
+                        .set("timestamp", pool_event.timestamp)
+                        .set("owner", &hex::decode(&collect.owner).unwrap())
+                        .set("amount0", amount0)
+                        .set("amount1", amount1)
+                        .set("amountUSD", amount_usd)
+                        .set_bigint("tickLower", &collect.tick_lower)
+                        .set_bigint("tickUpper", &collect.tick_upper)
+                        .set("logIndex", pool_event.log_index);
+                }
             };
         }
     }
@@ -1157,21 +1843,52 @@ pub fn swaps_mints_burns_created_entity_change(
 // --------------------
 //  Map Flashes Entities
 // --------------------
-// pub fn flashes_update_pool_fee_entity_change(tables: &mut Tables, flashes: Vec<events::Flash>) {
-//     // TODO: wut? flash updates would affect `fee_growth_global_0x_128` and `fee_growth_global_1x_128`?
-//     //  it's the business of TickUpdate, not of Flashes. Don't flashes produce some such updates?
-//     for flash in flashes {
-//         // tables.update_row("Pool", flash.pool_address.as_str());
-//         // .set(
-//         //     "feeGrowthGlobal0X128",
-//         //     BigInt::from(flash.fee_growth_global_0x_128.unwrap()),
-//         // )
-//         // .set(
-//         //     "feeGrowthGlobal1X128",
-//         //     BigInt::from(flash.fee_growth_global_1x_128.unwrap()),
-//         // );
-//     }
-// }
+pub fn flashes_created_entity_change(
+    tables: &mut Tables,
+    flashes: &Vec<events::Flash>,
+    store_eth_prices: &StoreGetBigDecimal,
+) {
+    for flash in flashes {
+        let ord = flash.log_ordinal;
+        let token0_addr = &flash.token0;
+        let token1_addr = &flash.token1;
+
+        let token0_derived_eth_price = store_eth_prices
+            .get_at(ord, format!("token:{token0_addr}:dprice:eth"))
+            .unwrap_or_default();
+        let token1_derived_eth_price = store_eth_prices
+            .get_at(ord, format!("token:{token1_addr}:dprice:eth"))
+            .unwrap_or_default();
+        let bundle_eth_price = store_eth_prices.get_at(ord, "bundle").unwrap_or_default();
+
+        let amount0 = BigDecimal::try_from(flash.amount_0.as_str()).unwrap();
+        let amount1 = BigDecimal::try_from(flash.amount_1.as_str()).unwrap();
+        let amount_usd = utils::calculate_amount_usd(
+            &amount0,
+            &amount1,
+            &token0_derived_eth_price,
+            &token1_derived_eth_price,
+            &bundle_eth_price,
+        );
+
+        // flashes are not counted in the pool's txCount, so the log index keeps the id unique
+        let transaction_id = &flash.transaction_id;
+        let log_index = flash.log_index;
+        tables
+            .create_row("Flash", format!("0x{transaction_id}#{log_index}"))
+            .set("transaction", format!("0x{transaction_id}"))
+            .set("timestamp", flash.timestamp)
+            .set("pool", format!("0x{}", flash.pool_address))
+            .set("sender", &hex::decode(&flash.sender).unwrap())
+            .set("recipient", &hex::decode(&flash.recipient).unwrap())
+            .set("amount0", amount0)
+            .set("amount1", amount1)
+            .set("amountUSD", amount_usd)
+            .set_bigdecimal("amount0Paid", &flash.amount_0_paid)
+            .set_bigdecimal("amount1Paid", &flash.amount_1_paid)
+            .set("logIndex", flash.log_index);
+    }
+}
 
 // --------------------
 //  Map Uniswap Day Data Entities
@@ -1386,6 +2103,9 @@ pub fn mint_burn_prices_pool_windows(
                 events::pool_event::Type::Swap(_) => {
                     continue; // the swap event will be taken care of by the prices_pool_windows
                 }
+                events::pool_event::Type::Collect(_) => {
+                    continue; // a collect doesn't move the price
+                }
                 _ => {}
             }
 
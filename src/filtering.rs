@@ -2,8 +2,7 @@ use crate::math::compute_price_from_tick_idx;
 use crate::pb::uniswap::events;
 use crate::storage::position_manager::PositionManagerStorage;
 use crate::storage::uniswap_v3_pool::UniswapPoolStorage;
-use crate::utils::NON_FUNGIBLE_POSITION_MANAGER;
-use crate::{abi, math, utils, BurnEvent, EventTrait, MintEvent, Pool, SwapEvent};
+use crate::{abi, math, tick_bitmap, utils, BurnEvent, CollectEvent, EventTrait, MintEvent, Pool, SwapEvent};
 use substreams::prelude::{BigDecimal, BigInt};
 use substreams::{log, Hex};
 use substreams_ethereum::block_view::CallView;
@@ -23,6 +22,7 @@ pub fn extract_pool_events_and_positions(
     log: &Log,
     call_view: &CallView,
     pool: &Pool,
+    position_manager: &Vec<u8>,
     timestamp_seconds: u64,
     block_number: u64,
 ) {
@@ -47,6 +47,17 @@ pub fn extract_pool_events_and_positions(
         let amount0 = swap.amount0.to_decimal(token0.decimals);
         let amount1 = swap.amount1.to_decimal(token1.decimals);
 
+        // the tick is only part of the storage changes when the swap moved it
+        let storage = UniswapPoolStorage::new(&call_view.call.storage_changes, &log.address);
+        let tick_before = match storage.slot0().tick() {
+            Some((old_tick, _)) => old_tick,
+            None => swap.tick.clone(),
+        };
+        let sqrt_price_before = match storage.slot0().sqrt_price_x96() {
+            Some((old_sqrt_price, _)) => old_sqrt_price,
+            None => swap.sqrt_price_x96.clone(),
+        };
+
         pool_events.push(events::PoolEvent {
             log_ordinal: log.ordinal,
             log_index: log.block_index as u64,
@@ -66,6 +77,8 @@ pub fn extract_pool_events_and_positions(
                 sqrt_price: swap.sqrt_price_x96.into(),
                 liquidity: swap.liquidity.into(),
                 tick: swap.tick.into(),
+                tick_before: tick_before.into(),
+                sqrt_price_before: sqrt_price_before.into(),
             })),
         });
 
@@ -78,6 +91,7 @@ pub fn extract_pool_events_and_positions(
                 collect_positions,
                 transfer_positions,
                 &position_manager_contract_call,
+                position_manager,
             );
         }
     } else if let Some(mint) = abi::pool::events::Mint::match_and_decode(log) {
@@ -122,37 +136,32 @@ pub fn extract_pool_events_and_positions(
             ..Default::default()
         };
 
-        let mut lower_tick = common_tick.clone();
-        let (price0, price1) = prices_from_tick_index(mint.tick_lower.to_i32());
-        lower_tick.idx = mint.tick_lower.as_ref().into();
-        lower_tick.price0 = price0.into();
-        lower_tick.price1 = price1.into();
-        ticks_created.push(lower_tick);
+        let storage = UniswapPoolStorage::new(&call_view.call.storage_changes, &log.address);
 
-        let mut upper_tick = common_tick.clone();
-        let (price0, price1) = prices_from_tick_index(mint.tick_upper.to_i32());
-        upper_tick.idx = mint.tick_upper.as_ref().into();
-        upper_tick.price0 = price0.into();
-        upper_tick.price1 = price1.into();
-        ticks_created.push(upper_tick);
+        // a tick is (re)initialized when its gross liquidity was zero before the mint
+        if tick_initialized_by_mint(&storage, &mint.tick_lower) {
+            let mut lower_tick = common_tick.clone();
+            let (price0, price1) = prices_from_tick_index(mint.tick_lower.to_i32());
+            lower_tick.idx = mint.tick_lower.as_ref().into();
+            lower_tick.price0 = price0.into();
+            lower_tick.price1 = price1.into();
+            ticks_created.push(lower_tick);
+        }
 
-        let storage = UniswapPoolStorage::new(&call_view.call.storage_changes, &log.address);
+        if tick_initialized_by_mint(&storage, &mint.tick_upper) {
+            let mut upper_tick = common_tick.clone();
+            let (price0, price1) = prices_from_tick_index(mint.tick_upper.to_i32());
+            upper_tick.idx = mint.tick_upper.as_ref().into();
+            upper_tick.price0 = price0.into();
+            upper_tick.price1 = price1.into();
+            ticks_created.push(upper_tick);
+        }
 
-        ticks_updated.push(events::TickUpdated {
-            idx: mint.tick_upper.as_ref().into(),
-            fee_growth_outside_0x_128: bigint_if_some(storage.ticks(&mint.tick_upper).fee_growth_outside_0_x128()),
-            fee_growth_outside_1x_128: bigint_if_some(storage.ticks(&mint.tick_upper).fee_growth_outside_1_x128()),
-            ..common_tick_updated.clone()
-        });
-        ticks_updated.push(events::TickUpdated {
-            idx: mint.tick_lower.as_ref().into(),
-            fee_growth_outside_0x_128: bigint_if_some(storage.ticks(&mint.tick_lower).fee_growth_outside_0_x128()),
-            fee_growth_outside_1x_128: bigint_if_some(storage.ticks(&mint.tick_lower).fee_growth_outside_1_x128()),
-            ..common_tick_updated.clone()
-        });
+        ticks_updated.push(tick_updated(&storage, &mint.tick_upper, &common_tick_updated));
+        ticks_updated.push(tick_updated(&storage, &mint.tick_lower, &common_tick_updated));
 
         if let Some(position_manager_contract_call) = call_view.parent() {
-            if position_manager_contract_call.address != NON_FUNGIBLE_POSITION_MANAGER {
+            if &position_manager_contract_call.address != position_manager {
                 return;
             }
 
@@ -204,6 +213,7 @@ pub fn extract_pool_events_and_positions(
                 collect_positions,
                 transfer_positions,
                 &position_manager_contract_call,
+                position_manager,
             );
         }
     } else if let Some(burn) = abi::pool::events::Burn::match_and_decode(log) {
@@ -243,18 +253,8 @@ pub fn extract_pool_events_and_positions(
 
         let storage = UniswapPoolStorage::new(&call_view.call.storage_changes, &log.address);
 
-        ticks_updated.push(events::TickUpdated {
-            idx: burn.tick_upper.as_ref().into(),
-            fee_growth_outside_0x_128: bigint_if_some(storage.ticks(&burn.tick_upper).fee_growth_outside_0_x128()),
-            fee_growth_outside_1x_128: bigint_if_some(storage.ticks(&burn.tick_upper).fee_growth_outside_1_x128()),
-            ..common_tick_updated.clone()
-        });
-        ticks_updated.push(events::TickUpdated {
-            idx: burn.tick_lower.as_ref().into(),
-            fee_growth_outside_0x_128: bigint_if_some(storage.ticks(&burn.tick_lower).fee_growth_outside_0_x128()),
-            fee_growth_outside_1x_128: bigint_if_some(storage.ticks(&burn.tick_lower).fee_growth_outside_1_x128()),
-            ..common_tick_updated.clone()
-        });
+        ticks_updated.push(tick_updated(&storage, &burn.tick_upper, &common_tick_updated));
+        ticks_updated.push(tick_updated(&storage, &burn.tick_lower, &common_tick_updated));
 
         if let Some(position_manager_contract_call) = call_view.parent() {
             extract_positions(
@@ -264,9 +264,40 @@ pub fn extract_pool_events_and_positions(
                 collect_positions,
                 transfer_positions,
                 &position_manager_contract_call,
+                position_manager,
             );
         }
-    } else if let Some(_collect) = abi::pool::events::Collect::match_and_decode(log) {
+    } else if let Some(collect) = abi::pool::events::Collect::match_and_decode(log) {
+        log::info!("COLLECT: transaction: {}", transaction_id.to_string());
+        if !pool.should_handle_mint_and_burn() {
+            return;
+        }
+
+        let token0 = pool.token0.as_ref().unwrap();
+        let token1 = pool.token1.as_ref().unwrap();
+        let amount0 = collect.amount0.to_decimal(token0.decimals);
+        let amount1 = collect.amount1.to_decimal(token1.decimals);
+
+        pool_events.push(events::PoolEvent {
+            log_ordinal: log.ordinal,
+            log_index: log.block_index as u64,
+            pool_address: pool.address.to_string(),
+            token0: token0.address.clone(),
+            token1: token1.address.clone(),
+            fee: pool.fee_tier.clone(),
+            transaction_id: transaction_id.to_string(),
+            timestamp: timestamp_seconds,
+            created_at_block_number: block_number,
+            r#type: Some(CollectEvent(events::pool_event::Collect {
+                owner: Hex(&collect.owner).to_string(),
+                recipient: Hex(&collect.recipient).to_string(),
+                amount_0: amount0.into(),
+                amount_1: amount1.into(),
+                tick_lower: collect.tick_lower.as_ref().into(),
+                tick_upper: collect.tick_upper.as_ref().into(),
+            })),
+        });
+
         if let Some(position_manager_contract_call) = call_view.parent() {
             extract_positions(
                 pool,
@@ -275,11 +306,35 @@ pub fn extract_pool_events_and_positions(
                 collect_positions,
                 transfer_positions,
                 &position_manager_contract_call,
+                position_manager,
             );
         };
     }
 }
 
+fn tick_initialized_by_mint(storage: &UniswapPoolStorage, tick_idx: &BigInt) -> bool {
+    match storage.ticks(tick_idx).liquidity_gross() {
+        Some((old_value, _new_value)) => old_value.eq(&BigInt::zero()),
+        None => false,
+    }
+}
+
+// the tick state after a Mint or a Burn, read from its storage changes
+fn tick_updated(storage: &UniswapPoolStorage, tick_idx: &BigInt, common: &events::TickUpdated) -> events::TickUpdated {
+    let tick = storage.ticks(tick_idx);
+    events::TickUpdated {
+        idx: tick_idx.to_string(),
+        fee_growth_outside_0x_128: bigint_if_some(tick.fee_growth_outside_0_x128()),
+        fee_growth_outside_1x_128: bigint_if_some(tick.fee_growth_outside_1_x128()),
+        liquidity_gross: bigint_if_some(tick.liquidity_gross()),
+        liquidity_net: bigint_if_some(tick.liquidity_net()),
+        tick_cumulative_outside: bigint_if_some(tick.tick_cumulative_outside()),
+        seconds_per_liquidity_outside_x128: bigint_if_some(tick.seconds_per_liquidity_outside_x128()),
+        seconds_outside: bigint_if_some(tick.seconds_outside()),
+        ..common.clone()
+    }
+}
+
 fn bigint_if_some(input: Option<(BigInt, BigInt)>) -> String {
     if let Some(el) = input {
         el.1.into()
@@ -290,7 +345,7 @@ fn bigint_if_some(input: Option<(BigInt, BigInt)>) -> String {
 
 fn prices_from_tick_index(tick_idx: i32) -> (BigDecimal, BigDecimal) {
     let price0 = compute_price_from_tick_idx(tick_idx);
-    let price1 = math::safe_div(&BigDecimal::from(1 as i32), &price0);
+    let price1 = math::inverse_price(&price0);
     (price0, price1)
 }
 
@@ -380,7 +435,7 @@ pub fn extract_fee_growth_update(
         }
         do_extract = true;
     } else if let Some(_) = abi::pool::events::Flash::match_and_decode(&log) {
-        do_extract = false; //TODO: handle this later when we process flashes
+        do_extract = true;
     }
     if do_extract {
         fee_growth_updates.append(&mut utils::extract_pool_fee_growth_global_updates(
@@ -422,6 +477,9 @@ pub fn extract_transactions(
     if abi::pool::events::Burn::match_and_decode(log).is_some()
         || abi::pool::events::Mint::match_and_decode(log).is_some()
         || abi::pool::events::Swap::match_and_decode(log).is_some()
+        || abi::pool::events::Flash::match_and_decode(log).is_some()
+        || abi::pool::events::Collect::match_and_decode(log).is_some()
+        || abi::pool::events::CollectProtocol::match_and_decode(log).is_some()
         || abi::positionmanager::events::IncreaseLiquidity::match_and_decode(log).is_some()
         || abi::positionmanager::events::Collect::match_and_decode(log).is_some()
         || abi::positionmanager::events::DecreaseLiquidity::match_and_decode(log).is_some()
@@ -447,9 +505,10 @@ fn extract_positions(
     collect_positions: &mut Vec<events::CollectPosition>,
     transfer_positions: &mut Vec<events::TransferPosition>,
     call: &Call,
+    position_manager: &Vec<u8>,
 ) {
     for log in call.logs.iter() {
-        if log.address != NON_FUNGIBLE_POSITION_MANAGER {
+        if &log.address != position_manager {
             return;
         }
 
@@ -471,6 +530,20 @@ fn extract_positions(
                 fee_growth_inside1_last_x128 = Some(new_value.to_string());
             }
 
+            let position_liquidity = manager_storage
+                .positions(&event.token_id)
+                .liquidity()
+                .map(|(_old_value, new_value)| new_value.to_string());
+
+            let tokens_owed0 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed0()
+                .map(|(_old_value, new_value)| new_value.to_string());
+            let tokens_owed1 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed1()
+                .map(|(_old_value, new_value)| new_value.to_string());
+
             increase_liquidity_positions.push(events::IncreaseLiquidityPosition {
                 token_id: event.token_id.to_string(),
                 liquidity: event.liquidity.to_string(),
@@ -478,6 +551,9 @@ fn extract_positions(
                 deposited_token1: event.amount1.to_decimal(pool.token1().decimals).to_string(),
                 fee_growth_inside0_last_x128,
                 fee_growth_inside1_last_x128,
+                position_liquidity,
+                tokens_owed0,
+                tokens_owed1,
                 log_ordinal: log.ordinal,
             });
         } else if let Some(event) = abi::positionmanager::events::DecreaseLiquidity::match_and_decode(log) {
@@ -492,6 +568,21 @@ fn extract_positions(
             {
                 fee_growth_inside1_last_x128 = Some(new_value.to_string());
             }
+
+            let position_liquidity = manager_storage
+                .positions(&event.token_id)
+                .liquidity()
+                .map(|(_old_value, new_value)| new_value.to_string());
+
+            let tokens_owed0 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed0()
+                .map(|(_old_value, new_value)| new_value.to_string());
+            let tokens_owed1 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed1()
+                .map(|(_old_value, new_value)| new_value.to_string());
+
             decrease_liquidity_positions.push(events::DecreaseLiquidityPosition {
                 token_id: event.token_id.to_string(),
                 liquidity: event.liquidity.to_string(),
@@ -499,6 +590,9 @@ fn extract_positions(
                 withdrawn_token1: event.amount1.to_decimal(pool.token1().decimals).to_string(),
                 fee_growth_inside0_last_x128,
                 fee_growth_inside1_last_x128,
+                position_liquidity,
+                tokens_owed0,
+                tokens_owed1,
                 log_ordinal: log.ordinal,
             });
         } else if let Some(event) = abi::positionmanager::events::Collect::match_and_decode(log) {
@@ -513,12 +607,24 @@ fn extract_positions(
             {
                 fee_growth_inside1_last_x128 = Some(new_value.to_string());
             }
+
+            let tokens_owed0 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed0()
+                .map(|(_old_value, new_value)| new_value.to_string());
+            let tokens_owed1 = manager_storage
+                .positions(&event.token_id)
+                .tokens_owed1()
+                .map(|(_old_value, new_value)| new_value.to_string());
+
             collect_positions.push(events::CollectPosition {
                 token_id: event.token_id.to_string(),
                 collected_fees_token0: event.amount0.to_decimal(pool.token0().decimals).to_string(),
                 collected_fees_token1: event.amount1.to_decimal(pool.token1().decimals).to_string(),
                 fee_growth_inside0_last_x128,
                 fee_growth_inside1_last_x128,
+                tokens_owed0,
+                tokens_owed1,
                 log_ordinal: log.ordinal,
             });
         } else if let Some(event) = abi::positionmanager::events::Transfer::match_and_decode(log) {
@@ -531,19 +637,371 @@ fn extract_positions(
     }
 }
 
-// pub fn extract_flashes(flashes: &mut Vec<events::Flash>, log: &Log) {
-//     if abi::pool::events::Flash::match_log(&log) {
-//         let pool_address: String = Hex(&log.address).to_string();
-//
-//         // FIXME: kill those `rpc` calls here!
-//         log::info!("pool_address: {}", pool_address);
-//         let (fee_growth_global_0x_128, fee_growth_global_1x_128) = rpc::fee_growth_global_x128_call(&pool_address);
-//
-//         flashes.push(events::Flash {
-//             pool_address,
-//             fee_growth_global_0x_128: fee_growth_global_0x_128.to_string(),
-//             fee_growth_global_1x_128: fee_growth_global_1x_128.to_string(),
-//             log_ordinal: log.ordinal,
-//         });
-//     }
-// }
+// positions are tracked at the pool level too, whoever the owner is: contracts calling the pool
+// directly, like vaults and routers, and the position manager holding the liquidity of all its NFTs
+pub fn extract_raw_positions(
+    raw_position_updates: &mut Vec<events::RawPositionUpdate>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+    transaction_id: &String,
+    timestamp_seconds: u64,
+    block_number: u64,
+) {
+    if !pool.should_handle_mint_and_burn() {
+        return;
+    }
+
+    let token0 = pool.token0_ref();
+    let token1 = pool.token1_ref();
+    let mut update = events::RawPositionUpdate {
+        pool_address: pool.address.to_string(),
+        token0: token0.address.clone(),
+        token1: token1.address.clone(),
+        transaction_id: transaction_id.to_string(),
+        log_ordinal: log.ordinal,
+        timestamp: timestamp_seconds,
+        block_number,
+        liquidity_delta: "0".to_string(),
+        deposited_token0: "0".to_string(),
+        deposited_token1: "0".to_string(),
+        withdrawn_token0: "0".to_string(),
+        withdrawn_token1: "0".to_string(),
+        collected_token0: "0".to_string(),
+        collected_token1: "0".to_string(),
+        ..Default::default()
+    };
+
+    let (owner, tick_lower, tick_upper) = if let Some(mint) = abi::pool::events::Mint::match_and_decode(log) {
+        update.liquidity_delta = mint.amount.to_string();
+        update.deposited_token0 = mint.amount0.to_decimal(token0.decimals).to_string();
+        update.deposited_token1 = mint.amount1.to_decimal(token1.decimals).to_string();
+        (mint.owner, mint.tick_lower.to_i32(), mint.tick_upper.to_i32())
+    } else if let Some(burn) = abi::pool::events::Burn::match_and_decode(log) {
+        update.liquidity_delta = burn.amount.neg().to_string();
+        update.withdrawn_token0 = burn.amount0.to_decimal(token0.decimals).to_string();
+        update.withdrawn_token1 = burn.amount1.to_decimal(token1.decimals).to_string();
+        (burn.owner, burn.tick_lower.to_i32(), burn.tick_upper.to_i32())
+    } else if let Some(collect) = abi::pool::events::Collect::match_and_decode(log) {
+        update.collected_token0 = collect.amount0.to_decimal(token0.decimals).to_string();
+        update.collected_token1 = collect.amount1.to_decimal(token1.decimals).to_string();
+        (collect.owner, collect.tick_lower.to_i32(), collect.tick_upper.to_i32())
+    } else {
+        return;
+    };
+
+    // the position values are only known when they changed
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+    let position = storage.positions(&owner, tick_lower, tick_upper);
+    update.fee_growth_inside0_last_x128 = position
+        .fee_growth_inside0_last_x128()
+        .map(|(_old_value, new_value)| new_value.to_string());
+    update.fee_growth_inside1_last_x128 = position
+        .fee_growth_inside1_last_x128()
+        .map(|(_old_value, new_value)| new_value.to_string());
+    update.tokens_owed0 = position
+        .tokens_owed0()
+        .map(|(_old_value, new_value)| new_value.to_string());
+    update.tokens_owed1 = position
+        .tokens_owed1()
+        .map(|(_old_value, new_value)| new_value.to_string());
+
+    update.owner = Hex(&owner).to_string();
+    update.tick_lower = tick_lower.to_string();
+    update.tick_upper = tick_upper.to_string();
+    raw_position_updates.push(update);
+}
+
+// only a Mint or a Burn can flip a tick, when its gross liquidity goes from or back to zero
+pub fn extract_tick_bitmap_updates(
+    tick_bitmap_updates: &mut Vec<events::TickBitmapUpdate>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+) {
+    if !pool.should_handle_mint_and_burn() || pool.tick_spacing <= 0 {
+        return;
+    }
+
+    let (tick_lower, tick_upper) = if let Some(mint) = abi::pool::events::Mint::match_and_decode(log) {
+        (mint.tick_lower.to_i32(), mint.tick_upper.to_i32())
+    } else if let Some(burn) = abi::pool::events::Burn::match_and_decode(log) {
+        (burn.tick_lower.to_i32(), burn.tick_upper.to_i32())
+    } else {
+        return;
+    };
+
+    let mut word_positions = vec![tick_bitmap::position(tick_lower, pool.tick_spacing).0];
+    let (upper_word_pos, _) = tick_bitmap::position(tick_upper, pool.tick_spacing);
+    if !word_positions.contains(&upper_word_pos) {
+        word_positions.push(upper_word_pos);
+    }
+
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+    for word_pos in word_positions {
+        if let Some((old_value, new_value)) = storage.tick_bitmap(word_pos) {
+            tick_bitmap_updates.push(events::TickBitmapUpdate {
+                pool_address: pool.address.to_string(),
+                word_pos: word_pos as i32,
+                old_value: math::u256_from_signed(&old_value.to_string()).to_string(),
+                new_value: math::u256_from_signed(&new_value.to_string()).to_string(),
+                log_ordinal: log.ordinal,
+            });
+        }
+    }
+}
+
+pub fn extract_flashes(
+    flashes: &mut Vec<events::Flash>,
+    log: &Log,
+    pool: &Pool,
+    transaction_id: &String,
+    timestamp_seconds: u64,
+) {
+    if let Some(flash) = abi::pool::events::Flash::match_and_decode(log) {
+        let token0 = pool.token0_ref();
+        let token1 = pool.token1_ref();
+
+        // the fee growth globals bumped by the flash fees are emitted by `extract_fee_growth_update`
+        flashes.push(events::Flash {
+            pool_address: pool.address.to_string(),
+            log_ordinal: log.ordinal,
+            log_index: log.block_index as u64,
+            token0: token0.address.clone(),
+            token1: token1.address.clone(),
+            transaction_id: transaction_id.to_string(),
+            timestamp: timestamp_seconds,
+            sender: Hex(&flash.sender).to_string(),
+            recipient: Hex(&flash.recipient).to_string(),
+            amount_0: flash.amount0.to_decimal(token0.decimals).into(),
+            amount_1: flash.amount1.to_decimal(token1.decimals).into(),
+            amount_0_paid: flash.paid0.to_decimal(token0.decimals).into(),
+            amount_1_paid: flash.paid1.to_decimal(token1.decimals).into(),
+        });
+    }
+}
+
+pub fn extract_protocol_fees(
+    fee_protocol_updates: &mut Vec<events::FeeProtocolUpdate>,
+    protocol_fees_accrued: &mut Vec<events::ProtocolFeesAccrued>,
+    collect_protocols: &mut Vec<events::CollectProtocol>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+    transaction_id: &String,
+    timestamp_seconds: u64,
+) {
+    let token0 = pool.token0_ref();
+    let token1 = pool.token1_ref();
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+
+    if let Some(event) = abi::pool::events::SetFeeProtocol::match_and_decode(log) {
+        // the event carries both values, but the packed slot0 byte is what the pool actually uses
+        let (fee_protocol_0, fee_protocol_1, fee_protocol_0_old, fee_protocol_1_old) =
+            match storage.slot0().fee_protocol() {
+                Some((old_value, new_value)) => {
+                    let old_value = old_value.to_u64() as u32;
+                    let new_value = new_value.to_u64() as u32;
+                    (new_value % 16, new_value >> 4, old_value % 16, old_value >> 4)
+                }
+                None => (
+                    event.fee_protocol0_new.to_u64() as u32,
+                    event.fee_protocol1_new.to_u64() as u32,
+                    event.fee_protocol0_old.to_u64() as u32,
+                    event.fee_protocol1_old.to_u64() as u32,
+                ),
+            };
+
+        fee_protocol_updates.push(events::FeeProtocolUpdate {
+            pool_address: pool.address.to_string(),
+            log_ordinal: log.ordinal,
+            fee_protocol_0,
+            fee_protocol_1,
+            fee_protocol_0_old,
+            fee_protocol_1_old,
+        });
+    } else if let Some(event) = abi::pool::events::CollectProtocol::match_and_decode(log) {
+        collect_protocols.push(events::CollectProtocol {
+            pool_address: pool.address.to_string(),
+            log_ordinal: log.ordinal,
+            log_index: log.block_index as u64,
+            token0: token0.address.clone(),
+            token1: token1.address.clone(),
+            transaction_id: transaction_id.to_string(),
+            timestamp: timestamp_seconds,
+            sender: Hex(&event.sender).to_string(),
+            recipient: Hex(&event.recipient).to_string(),
+            amount_0: event.amount0.to_decimal(token0.decimals).into(),
+            amount_1: event.amount1.to_decimal(token1.decimals).into(),
+        });
+    } else if abi::pool::events::Swap::match_and_decode(log).is_some()
+        || abi::pool::events::Flash::match_and_decode(log).is_some()
+    {
+        // only swaps and flashes grow the protocol fees, collectProtocol is the only thing shrinking them
+        let amount_0 = protocol_fees_delta(storage.protocol_fees_token0());
+        let amount_1 = protocol_fees_delta(storage.protocol_fees_token1());
+        if amount_0.eq(&BigInt::zero()) && amount_1.eq(&BigInt::zero()) {
+            return;
+        }
+
+        protocol_fees_accrued.push(events::ProtocolFeesAccrued {
+            pool_address: pool.address.to_string(),
+            log_ordinal: log.ordinal,
+            token0: token0.address.clone(),
+            token1: token1.address.clone(),
+            amount_0: amount_0.to_decimal(token0.decimals).into(),
+            amount_1: amount_1.to_decimal(token1.decimals).into(),
+        });
+    }
+}
+
+fn protocol_fees_delta(input: Option<(BigInt, BigInt)>) -> BigInt {
+    match input {
+        Some((old_value, new_value)) if new_value.gt(&old_value) => new_value - old_value,
+        _ => BigInt::zero(),
+    }
+}
+
+pub fn extract_pool_oracle_updates(
+    pool_oracle_updates: &mut Vec<events::PoolOracle>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+) {
+    let event_cardinality_next = abi::pool::events::IncreaseObservationCardinalityNext::match_and_decode(log)
+        .map(|event| event.observation_cardinality_next_new.to_u64() as u32);
+
+    // observations are written by the swaps, mints and burns and grown by increaseObservationCardinalityNext
+    if event_cardinality_next.is_none()
+        && abi::pool::events::Initialize::match_and_decode(log).is_none()
+        && abi::pool::events::Swap::match_and_decode(log).is_none()
+        && abi::pool::events::Mint::match_and_decode(log).is_none()
+        && abi::pool::events::Burn::match_and_decode(log).is_none()
+    {
+        return;
+    }
+
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+    let slot0 = storage.slot0();
+    let new_value = |input: Option<(BigInt, BigInt)>| input.map(|(_, new_value)| new_value.to_u64() as u32);
+
+    let oracle = events::PoolOracle {
+        pool_address: pool.address.to_string(),
+        log_ordinal: log.ordinal,
+        observation_index: new_value(slot0.observation_index()),
+        observation_cardinality: new_value(slot0.observation_cardinality()),
+        observation_cardinality_next: new_value(slot0.observation_cardinality_next()).or(event_cardinality_next),
+    };
+
+    if oracle.observation_index.is_none()
+        && oracle.observation_cardinality.is_none()
+        && oracle.observation_cardinality_next.is_none()
+    {
+        return;
+    }
+    pool_oracle_updates.push(oracle);
+}
+
+pub fn extract_pool_observations(
+    observations: &mut Vec<events::Observation>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+) {
+    if abi::pool::events::Initialize::match_and_decode(log).is_none()
+        && abi::pool::events::Swap::match_and_decode(log).is_none()
+        && abi::pool::events::Mint::match_and_decode(log).is_none()
+        && abi::pool::events::Burn::match_and_decode(log).is_none()
+    {
+        return;
+    }
+
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+    for index in storage.observation_indexes() {
+        let observation = storage.observations(index);
+        // slots prepared by increaseObservationCardinalityNext are not initialized and hold no data
+        if !observation.initialized().map_or(false, |(_, initialized)| initialized) {
+            continue;
+        }
+
+        observations.push(events::Observation {
+            pool_address: pool.address.to_string(),
+            log_ordinal: log.ordinal,
+            index,
+            block_timestamp: bigint_if_some(observation.block_timestamp()).parse::<u32>().unwrap(),
+            tick_cumulative: bigint_if_some(observation.tick_cumulative()),
+            seconds_per_liquidity_cumulative_x128: bigint_if_some(observation.seconds_per_liquidity_cumulative_x128()),
+            initialized: true,
+        });
+    }
+}
+
+pub fn extract_ticks_crossed(
+    ticks_crossed: &mut Vec<events::TickCrossed>,
+    log: &Log,
+    storage_changes: &Vec<StorageChange>,
+    pool: &Pool,
+    transaction_id: &String,
+    timestamp_seconds: u64,
+) {
+    if abi::pool::events::Swap::match_and_decode(log).is_none() || pool.tick_spacing <= 0 {
+        return;
+    }
+
+    // a swap which did not move the tick did not cross any
+    let storage = UniswapPoolStorage::new(storage_changes, &log.address);
+    let (tick_before, tick_after) = match storage.slot0().tick() {
+        Some((old_tick, new_tick)) => (old_tick.to_i32(), new_tick.to_i32()),
+        None => return,
+    };
+
+    // crossing a tick flips its fee growths outside, going down it is crossed when the tick ends up below it.
+    // The swap only changes the hashed slots of the ticks it crosses, the walk over the aligned ticks traversed
+    // stops once they are all found
+    let mut changed_slots = storage.changed_map_slots();
+    let low = tick_before.min(tick_after);
+    let high = tick_before.max(tick_after);
+    let (mut tick_idx, step) = if tick_after > tick_before {
+        (
+            (low.div_euclid(pool.tick_spacing) + 1) * pool.tick_spacing,
+            pool.tick_spacing,
+        )
+    } else {
+        (
+            high.div_euclid(pool.tick_spacing) * pool.tick_spacing,
+            -pool.tick_spacing,
+        )
+    };
+
+    while !changed_slots.is_empty() && low < tick_idx && tick_idx <= high {
+        let crossed_tick_idx = tick_idx;
+        tick_idx += step;
+
+        let tick = storage.ticks(&BigInt::from(crossed_tick_idx));
+        let mut crossed = false;
+        for slot in tick.crossing_slots() {
+            crossed |= changed_slots.remove(&slot);
+        }
+        if !crossed {
+            continue;
+        }
+
+        let fee_growth_outside_0_x128 = tick.fee_growth_outside_0_x128();
+        let fee_growth_outside_1_x128 = tick.fee_growth_outside_1_x128();
+        let tick_cumulative_outside = tick.tick_cumulative_outside();
+        let seconds_per_liquidity_outside_x128 = tick.seconds_per_liquidity_outside_x128();
+        let seconds_outside = tick.seconds_outside();
+        ticks_crossed.push(events::TickCrossed {
+            pool_address: pool.address.to_string(),
+            idx: crossed_tick_idx.to_string(),
+            log_ordinal: log.ordinal,
+            fee_growth_outside_0x_128: bigint_if_some(fee_growth_outside_0_x128),
+            fee_growth_outside_1x_128: bigint_if_some(fee_growth_outside_1_x128),
+            timestamp: timestamp_seconds,
+            transaction_id: transaction_id.to_string(),
+            tick_cumulative_outside: bigint_if_some(tick_cumulative_outside),
+            seconds_per_liquidity_outside_x128: bigint_if_some(seconds_per_liquidity_outside_x128),
+            seconds_outside: bigint_if_some(seconds_outside),
+        });
+    }
+}
@@ -2,30 +2,41 @@ extern crate core;
 
 pub mod abi;
 mod ast;
+mod config;
 mod db;
 mod eth;
 mod filtering;
 mod key;
 mod math;
 mod pb;
+mod position_index;
 mod price;
 mod rpc;
 mod storage;
-mod ticks_idx;
+mod tick_bitmap;
+mod tick_ranges;
+mod twap;
 mod utils;
 
+use crate::config::NetworkConfig;
 use crate::ethpb::v2::{Block, StorageChange};
 use crate::pb::uniswap;
 use crate::pb::uniswap::events::pool_event::Type;
-use crate::pb::uniswap::events::pool_event::Type::{Burn as BurnEvent, Mint as MintEvent, Swap as SwapEvent};
+use crate::pb::uniswap::events::pool_event::Type::{
+    Burn as BurnEvent, Collect as CollectEvent, Mint as MintEvent, Swap as SwapEvent,
+};
 use crate::pb::uniswap::events::position_event::Type::{
     CollectPosition, CreatedPosition, DecreaseLiquidityPosition, IncreaseLiquidityPosition, TransferPosition,
 };
 use crate::pb::uniswap::events::{PoolSqrtPrice, PositionEvent};
 use crate::pb::uniswap::{events, Events};
-use crate::pb::uniswap::{Erc20Token, Erc20Tokens, Pool, Pools};
-use crate::price::WHITELIST_TOKENS;
-use crate::utils::{ERROR_POOL, UNISWAP_V3_FACTORY};
+use crate::pb::uniswap::{
+    factory_events, BundlePriceSource, DerivedPrice, DerivedPrices, Erc20Token, Erc20Tokens, FactoryEvents,
+    InitializedTicks, Pool, PoolInitializedTicks, PoolTwap, PoolTwaps, Pools, PositionAmount, PositionAmounts,
+    PriceOutlier, PriceOutliers, QuotePrice, TokenPrice,
+};
+use ethabi::ethereum_types::U256;
+use std::collections::{BTreeSet, HashSet};
 use std::ops::{Div, Mul, Sub};
 use substreams::errors::Error;
 use substreams::key::key_first_segment_in;
@@ -42,62 +53,94 @@ use substreams_entity_change::tables::Tables;
 use substreams_ethereum::{pb::eth as ethpb, Event as EventTrait};
 
 #[substreams::handlers::map]
-pub fn map_pools_created(block: Block) -> Result<Pools, Error> {
+pub fn map_pools_created(params: String, block: Block) -> Result<Pools, Error> {
     use abi::factory::events::PoolCreated;
 
-    Ok(Pools {
-        pools: block
-            .events::<PoolCreated>(&[&UNISWAP_V3_FACTORY])
+    let config = NetworkConfig::from_params(&params);
+    let factory_address = config.factory_address();
+
+    // the seeded pools have no `PoolCreated` event, they are created at the start block from their contract calls
+    let mut pools = vec![];
+    if block.number == config.start_block {
+        for pool_address in config.seeded_pools.iter() {
+            match rpc::pool_created_call(pool_address) {
+                Some(event) => pools.extend(create_pool(&config, &block, event, "".to_string(), 0)),
+                None => log::info!("seeded pool {} not found", pool_address),
+            }
+        }
+    }
+
+    pools.extend(
+        block
+            .events::<PoolCreated>(&[&factory_address])
             .filter_map(|(event, log)| {
-                log::info!("pool addr: {}", Hex(&event.pool));
+                create_pool(
+                    &config,
+                    &block,
+                    event,
+                    Hex(&log.receipt.transaction.hash).to_string(),
+                    log.ordinal(),
+                )
+            }),
+    );
 
-                if event.pool == ERROR_POOL {
-                    return None;
-                }
+    Ok(Pools { pools })
+}
 
-                let token0_address = Hex(&event.token0).to_string();
-                let token1_address = Hex(&event.token1).to_string();
-
-                //todo: question regarding the ignore_pool line. In the
-                // uniswap-v3 subgraph, they seem to bail out when they
-                // match the addr, should we do the same ?
-                Some(Pool {
-                    address: Hex(&log.data()[44..64]).to_string(),
-                    transaction_id: Hex(&log.receipt.transaction.hash).to_string(),
-                    created_at_block_number: block.number,
-                    created_at_timestamp: block.timestamp_seconds(),
-                    fee_tier: event.fee.to_string(),
-                    tick_spacing: event.tick_spacing.into(),
-                    log_ordinal: log.ordinal(),
-                    ignore_pool: event.pool == ERROR_POOL,
-                    token0: Some(match rpc::create_uniswap_token(&token0_address) {
-                        Some(mut token) => {
-                            token.total_supply = rpc::token_total_supply_call(&token0_address)
-                                .unwrap_or(BigInt::zero())
-                                .to_string();
-                            token
-                        }
-                        None => {
-                            // We were unable to create the uniswap token, so we discard this event entirely
-                            return None;
-                        }
-                    }),
-                    token1: Some(match rpc::create_uniswap_token(&token1_address) {
-                        Some(mut token) => {
-                            token.total_supply = rpc::token_total_supply_call(&token1_address)
-                                .unwrap_or(BigInt::zero())
-                                .to_string();
-                            token
-                        }
-                        None => {
-                            // We were unable to create the uniswap token, so we discard this event entirely
-                            return None;
-                        }
-                    }),
-                    ..Default::default()
-                })
-            })
-            .collect(),
+fn create_pool(
+    config: &NetworkConfig,
+    block: &Block,
+    event: abi::factory::events::PoolCreated,
+    transaction_id: String,
+    log_ordinal: u64,
+) -> Option<Pool> {
+    log::info!("pool addr: {}", Hex(&event.pool));
+
+    let ignore_pool = config.is_ignored_pool(&Hex(&event.pool).to_string());
+    if ignore_pool {
+        return None;
+    }
+
+    let token0_address = Hex(&event.token0).to_string();
+    let token1_address = Hex(&event.token1).to_string();
+
+    //todo: question regarding the ignore_pool line. In the
+    // uniswap-v3 subgraph, they seem to bail out when they
+    // match the addr, should we do the same ?
+    Some(Pool {
+        address: Hex(&event.pool).to_string(),
+        transaction_id,
+        created_at_block_number: block.number,
+        created_at_timestamp: block.timestamp_seconds(),
+        fee_tier: event.fee.to_string(),
+        tick_spacing: event.tick_spacing.into(),
+        log_ordinal,
+        ignore_pool,
+        token0: Some(match rpc::create_uniswap_token(&token0_address, config) {
+            Some(mut token) => {
+                token.total_supply = rpc::token_total_supply_call(&token0_address)
+                    .unwrap_or(BigInt::zero())
+                    .to_string();
+                token
+            }
+            None => {
+                // We were unable to create the uniswap token, so we discard this event entirely
+                return None;
+            }
+        }),
+        token1: Some(match rpc::create_uniswap_token(&token1_address, config) {
+            Some(mut token) => {
+                token.total_supply = rpc::token_total_supply_call(&token1_address)
+                    .unwrap_or(BigInt::zero())
+                    .to_string();
+                token
+            }
+            None => {
+                // We were unable to create the uniswap token, so we discard this event entirely
+                return None;
+            }
+        }),
+        ..Default::default()
     })
 }
 
@@ -131,15 +174,50 @@ pub fn store_pool_count(pools: Pools, store: StoreAddBigInt) {
 }
 
 #[substreams::handlers::map]
-pub fn map_tokens_whitelist_pools(pools: Pools) -> Result<Erc20Tokens, Error> {
+pub fn map_factory_events(params: String, block: Block) -> Result<FactoryEvents, Error> {
+    use abi::factory::events::{FeeAmountEnabled, OwnerChanged};
+
+    let config = NetworkConfig::from_params(&params);
+    let factory_address = config.factory_address();
+    let timestamp = block.timestamp_seconds();
+
+    Ok(FactoryEvents {
+        fee_amounts_enabled: block
+            .events::<FeeAmountEnabled>(&[&factory_address])
+            .map(|(event, log)| factory_events::FeeAmountEnabled {
+                fee: event.fee.to_string(),
+                tick_spacing: event.tick_spacing.into(),
+                transaction_id: Hex(&log.receipt.transaction.hash).to_string(),
+                timestamp,
+                block_number: block.number,
+                log_ordinal: log.ordinal(),
+            })
+            .collect(),
+        owner_changes: block
+            .events::<OwnerChanged>(&[&factory_address])
+            .map(|(event, log)| factory_events::OwnerChanged {
+                old_owner: Hex(&event.old_owner).to_string(),
+                new_owner: Hex(&event.new_owner).to_string(),
+                transaction_id: Hex(&log.receipt.transaction.hash).to_string(),
+                timestamp,
+                block_number: block.number,
+                log_ordinal: log.ordinal(),
+            })
+            .collect(),
+    })
+}
+
+#[substreams::handlers::map]
+pub fn map_tokens_whitelist_pools(params: String, pools: Pools) -> Result<Erc20Tokens, Error> {
+    let config = NetworkConfig::from_params(&params);
     let mut tokens = vec![];
 
     for pool in pools.pools {
         let mut token0 = pool.token0();
         let mut token1 = pool.token1();
 
-        let token0_whitelisted = WHITELIST_TOKENS.contains(&token0.address.as_str());
-        let token1_whitelisted = WHITELIST_TOKENS.contains(&token1.address.as_str());
+        let token0_whitelisted = config.pricing.is_whitelisted(&token0.address);
+        let token1_whitelisted = config.pricing.is_whitelisted(&token1.address);
 
         if token0_whitelisted {
             log::info!("adding pool: {} to token: {}", pool.address, token1.address);
@@ -164,8 +242,21 @@ pub fn store_tokens_whitelist_pools(tokens: Erc20Tokens, output_append: StoreApp
     }
 }
 
+#[substreams::handlers::store]
+pub fn store_token_pools(pools: Pools, output_append: StoreAppend<String>) {
+    for pool in pools.pools {
+        let token0_addr = pool.token0_ref().address();
+        let token1_addr = pool.token1_ref().address();
+
+        output_append.append(pool.log_ordinal, format!("token:{token0_addr}"), pool.address.clone());
+        output_append.append(pool.log_ordinal, format!("token:{token1_addr}"), pool.address.clone());
+    }
+}
+
 #[substreams::handlers::map]
-pub fn map_extract_data_types(block: Block, pools_store: StoreGetProto<Pool>) -> Result<Events, Error> {
+pub fn map_extract_data_types(params: String, block: Block, pools_store: StoreGetProto<Pool>) -> Result<Events, Error> {
+    let config = NetworkConfig::from_params(&params);
+    let position_manager = config.position_manager_address();
     let mut events = Events::default();
 
     let mut pool_sqrt_prices: Vec<events::PoolSqrtPrice> = vec![];
@@ -173,15 +264,23 @@ pub fn map_extract_data_types(block: Block, pools_store: StoreGetProto<Pool>) ->
     let mut fee_growth_global_updates: Vec<events::FeeGrowthGlobal> = vec![];
     let mut pool_events: Vec<events::PoolEvent> = vec![];
     let mut transactions: Vec<events::Transaction> = vec![];
-    // let mut flashes: Vec<events::Flash> = vec![];
+    let mut flashes: Vec<events::Flash> = vec![];
+    let mut fee_protocol_updates: Vec<events::FeeProtocolUpdate> = vec![];
+    let mut protocol_fees_accrued: Vec<events::ProtocolFeesAccrued> = vec![];
+    let mut collect_protocols: Vec<events::CollectProtocol> = vec![];
+    let mut pool_oracle_updates: Vec<events::PoolOracle> = vec![];
+    let mut observations: Vec<events::Observation> = vec![];
     let mut ticks_created: Vec<events::TickCreated> = vec![];
     let mut ticks_updated: Vec<events::TickUpdated> = vec![];
+    let mut ticks_crossed: Vec<events::TickCrossed> = vec![];
 
     let mut positions_created: Vec<events::CreatedPosition> = vec![];
     let mut positions_increase_liquidity: Vec<events::IncreaseLiquidityPosition> = vec![];
     let mut positions_decrease_liquidity: Vec<events::DecreaseLiquidityPosition> = vec![];
     let mut positions_collect: Vec<events::CollectPosition> = vec![];
     let mut positions_transfer: Vec<events::TransferPosition> = vec![];
+    let mut raw_position_updates: Vec<events::RawPositionUpdate> = vec![];
+    let mut tick_bitmap_updates: Vec<events::TickBitmapUpdate> = vec![];
 
     let timestamp = block.timestamp_seconds();
 
@@ -204,6 +303,15 @@ pub fn map_extract_data_types(block: Block, pools_store: StoreGetProto<Pool>) ->
                 &pool,
             );
 
+            filtering::extract_pool_oracle_updates(
+                &mut pool_oracle_updates,
+                log,
+                &call_view.call.storage_changes,
+                &pool,
+            );
+
+            filtering::extract_pool_observations(&mut observations, log, &call_view.call.storage_changes, &pool);
+
             filtering::extract_pool_events_and_positions(
                 &mut pool_events,
                 &mut ticks_created,
@@ -218,13 +326,51 @@ pub fn map_extract_data_types(block: Block, pools_store: StoreGetProto<Pool>) ->
                 log,
                 &call_view,
                 &pool,
+                &position_manager,
                 timestamp,
                 block.number,
             );
 
+            filtering::extract_raw_positions(
+                &mut raw_position_updates,
+                log,
+                &call_view.call.storage_changes,
+                &pool,
+                &transactions_id,
+                timestamp,
+                block.number,
+            );
+
+            filtering::extract_tick_bitmap_updates(
+                &mut tick_bitmap_updates,
+                log,
+                &call_view.call.storage_changes,
+                &pool,
+            );
+
+            filtering::extract_ticks_crossed(
+                &mut ticks_crossed,
+                log,
+                &call_view.call.storage_changes,
+                &pool,
+                &transactions_id,
+                timestamp,
+            );
+
             filtering::extract_transactions(&mut transactions, log, &trx, timestamp, block.number);
 
-            // filtering::extract_flashes(&mut flashes, &log);
+            filtering::extract_flashes(&mut flashes, log, &pool, &transactions_id, timestamp);
+
+            filtering::extract_protocol_fees(
+                &mut fee_protocol_updates,
+                &mut protocol_fees_accrued,
+                &mut collect_protocols,
+                log,
+                &call_view.call.storage_changes,
+                &pool,
+                &transactions_id,
+                timestamp,
+            );
         }
     }
 
@@ -238,9 +384,17 @@ pub fn map_extract_data_types(block: Block, pools_store: StoreGetProto<Pool>) ->
     events.decrease_liquidity_positions = positions_decrease_liquidity;
     events.collect_positions = positions_collect;
     events.transfer_positions = positions_transfer;
-    // events.flashes = flashes;
+    events.raw_position_updates = raw_position_updates;
+    events.tick_bitmap_updates = tick_bitmap_updates;
+    events.flashes = flashes;
+    events.fee_protocol_updates = fee_protocol_updates;
+    events.protocol_fees_accrued = protocol_fees_accrued;
+    events.collect_protocols = collect_protocols;
+    events.pool_oracle_updates = pool_oracle_updates;
+    events.observations = observations;
     events.ticks_created = ticks_created;
     events.ticks_updated = ticks_updated;
+    events.ticks_crossed = ticks_crossed;
 
     Ok(events)
 }
@@ -253,6 +407,104 @@ pub fn store_pool_sqrt_price(events: Events, store: StoreSetProto<PoolSqrtPrice>
     }
 }
 
+#[substreams::handlers::store]
+pub fn store_pool_observations(events: Events, store: StoreSetProto<events::Observation>) {
+    for observation in events.observations {
+        let pool_address = &observation.pool_address;
+        let index = observation.index;
+        store.set(
+            observation.log_ordinal,
+            format!("pool:{pool_address}:observation:{index}"),
+            &observation,
+        );
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_pool_oracle(events: Events, store: StoreSetBigInt) {
+    for update in events.pool_oracle_updates {
+        let pool_address = &update.pool_address;
+        if let Some(observation_index) = update.observation_index {
+            store.set(
+                update.log_ordinal,
+                format!("pool:{pool_address}:observationIndex"),
+                &BigInt::from(observation_index),
+            );
+        }
+        if let Some(observation_cardinality) = update.observation_cardinality {
+            store.set(
+                update.log_ordinal,
+                format!("pool:{pool_address}:observationCardinality"),
+                &BigInt::from(observation_cardinality),
+            );
+        }
+    }
+}
+
+#[substreams::handlers::map]
+pub fn map_pool_twaps(
+    params: String,
+    clock: Clock,
+    events: Events,
+    observations_store: StoreGetProto<events::Observation>,
+    oracle_store: StoreGetBigInt,
+    pool_sqrt_price_store: StoreGetProto<PoolSqrtPrice>,
+) -> Result<PoolTwaps, Error> {
+    let windows = twap::parse_windows(&params);
+    let timestamp = clock.timestamp.unwrap().seconds as u64;
+
+    // only the pools that were touched in this block get a new twap
+    let mut pool_addresses: Vec<&String> = vec![];
+    for pool_address in events
+        .pool_sqrt_prices
+        .iter()
+        .map(|sqrt_price| &sqrt_price.pool_address)
+        .chain(events.observations.iter().map(|observation| &observation.pool_address))
+    {
+        if !pool_addresses.contains(&pool_address) {
+            pool_addresses.push(pool_address);
+        }
+    }
+
+    let mut twaps: Vec<PoolTwap> = vec![];
+    for pool_address in pool_addresses {
+        let tick = match pool_sqrt_price_store.get_last(format!("pool:{pool_address}")) {
+            Some(sqrt_price) => sqrt_price.tick.parse::<i32>().unwrap(),
+            None => continue,
+        };
+        let index = oracle_store
+            .get_last(format!("pool:{pool_address}:observationIndex"))
+            .map_or(0, |index| index.to_u64() as u32);
+        let cardinality = oracle_store
+            .get_last(format!("pool:{pool_address}:observationCardinality"))
+            .map_or(1, |cardinality| cardinality.to_u64() as u32);
+
+        for window in &windows {
+            let mean_tick = twap::arithmetic_mean_tick(timestamp, *window, tick, index, cardinality, |i| {
+                observations_store
+                    .get_last(format!("pool:{pool_address}:observation:{i}"))
+                    .map(|observation| twap::ObservationPoint::from(&observation))
+            });
+
+            if let Some(mean_tick) = mean_tick {
+                let price0 = math::compute_price_from_tick_idx(mean_tick);
+                let price1 = math::inverse_price(&price0);
+                twaps.push(PoolTwap {
+                    pool_address: pool_address.to_string(),
+                    timestamp,
+                    block_number: clock.number,
+                    window: *window,
+                    arithmetic_mean_tick: mean_tick,
+                    price0: price0.to_string(),
+                    price1: price1.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(PoolTwaps { twaps })
+}
+
 #[substreams::handlers::store]
 pub fn store_prices(clock: Clock, events: Events, pools_store: StoreGetProto<Pool>, store: StoreSetBigDecimal) {
     let timestamp_seconds = clock.timestamp.unwrap().seconds;
@@ -287,7 +539,7 @@ pub fn store_prices(clock: Clock, events: Events, pools_store: StoreGetProto<Poo
                     token1.address
                 );
 
-                let sqrt_price = BigDecimal::try_from(sqrt_price_update.sqrt_price).unwrap();
+                let sqrt_price = U256::from_dec_str(&sqrt_price_update.sqrt_price).unwrap();
                 log::debug!("sqrtPrice: {}", sqrt_price.to_string());
 
                 let tokens_price: (BigDecimal, BigDecimal) =
@@ -379,7 +631,6 @@ pub fn store_total_tx_counts(clock: Clock, events: Events, output: StoreAddBigIn
     let hour_id = timestamp_seconds / 3600;
     let prev_day_id = day_id - 1;
     let prev_hour_id = hour_id - 1;
-    let factory_addr = Hex(UNISWAP_V3_FACTORY);
 
     output.delete_prefix(0, &format!("UniswapDayData:{prev_day_id}:"));
     output.delete_prefix(0, &format!("PoolDayData:{prev_day_id}:"));
@@ -398,7 +649,7 @@ pub fn store_total_tx_counts(clock: Clock, events: Events, output: StoreAddBigIn
                 format!("pool:{pool_address}"),
                 format!("token:{token0_addr}"),
                 format!("token:{token1_addr}"),
-                format!("factory:{factory_addr}"),
+                format!("factory:txCount"),
                 format!("UniswapDayData:{day_id}"),
                 format!("PoolDayData:{day_id}:{pool_address}"),
                 format!("PoolHourData:{hour_id}:{pool_address}"),
@@ -452,8 +703,65 @@ pub fn store_native_amounts(events: Events, store: StoreSetBigDecimal) {
     }
 }
 
-#[substreams::handlers::store]
-pub fn store_eth_prices(
+#[substreams::handlers::map]
+pub fn map_price_outliers(
+    params: String,
+    events: Events,                         /* map_extract_data_types */
+    pools_store: StoreGetProto<Pool>,       /* store_pools_created */
+    prices_store: StoreGetBigDecimal,       /* store_prices */
+    pool_liquidities_store: StoreGetBigInt, /* store_pool_liquidities */
+) -> Result<PriceOutliers, Error> {
+    let config = NetworkConfig::from_params(&params);
+    let mut outliers = vec![];
+
+    let max_jump = match &config.pricing.max_price_jump {
+        None => return Ok(PriceOutliers { outliers }),
+        Some(max_jump) => max_jump,
+    };
+
+    for pool_sqrt_price in events.pool_sqrt_prices {
+        if pool_sqrt_price.initialized {
+            continue;
+        }
+
+        let ord = pool_sqrt_price.ordinal;
+        let pool_address = &pool_sqrt_price.pool_address;
+        let pool = match pools_store.get_last(format!("pool:{pool_address}")) {
+            None => continue,
+            Some(pool) => pool,
+        };
+        let token0_addr = pool.token0_ref().address();
+
+        // values at ordinal 0 are the ones left by the previous block, a pool without
+        // liquidity at the end of the previous block has no price to compare to
+        let previous_liquidity = match pool_liquidities_store.get_at(0, format!("pool:{pool_address}")) {
+            Some(liquidity) if liquidity.gt(&BigInt::zero()) => liquidity,
+            _ => continue,
+        };
+        let price_key = format!("pool:{pool_address}:{token0_addr}:token0");
+        let (previous_price, price) = match (prices_store.get_at(0, &price_key), prices_store.get_at(ord, &price_key)) {
+            (Some(previous_price), Some(price)) => (previous_price, price),
+            _ => continue,
+        };
+
+        if price::is_price_outlier(&previous_price, &price, max_jump) {
+            log::info!("pool {pool_address} price moved from {previous_price} to {price} at ordinal {ord}");
+            outliers.push(PriceOutlier {
+                pool_address: pool_address.clone(),
+                ordinal: ord,
+                previous_price0: previous_price.to_string(),
+                price0: price.to_string(),
+                previous_liquidity: previous_liquidity.to_string(),
+            });
+        }
+    }
+
+    Ok(PriceOutliers { outliers })
+}
+
+#[substreams::handlers::map]
+pub fn map_eth_prices(
+    params: String,
     clock: Clock,
     events: Events,                                /* map_extract_data_types */
     pools_store: StoreGetProto<Pool>,              /* store_pools_created */
@@ -461,19 +769,27 @@ pub fn store_eth_prices(
     tokens_whitelist_pools_store: StoreGetRaw,     /* store_tokens_whitelist_pools */
     total_native_amount_store: StoreGetBigDecimal, /* store_native_amounts */
     pool_liquidities_store: StoreGetBigInt,        /* store_pool_liquidities */
-    output: StoreSetBigDecimal,
-) {
-    let timestamp_seconds = clock.timestamp.unwrap().seconds;
-    let day_id = timestamp_seconds / 86400;
-    let hour_id = timestamp_seconds / 3600;
-    let prev_day_id = day_id - 1;
-    let prev_hour_id = hour_id - 1;
-
-    output.delete_prefix(0, &format!("TokenDayData:{prev_day_id}:"));
-    output.delete_prefix(0, &format!("TokenHourData:{prev_hour_id}:"));
+    token_pools_store: StoreGetRaw,                /* store_token_pools */
+    price_outliers: PriceOutliers,                 /* map_price_outliers */
+) -> Result<DerivedPrices, Error> {
+    let config = NetworkConfig::from_params(&params);
+    let pool_prices = price::PoolPrices::new(&prices_store, &pools_store, &price_outliers);
+    let mut prices = vec![];
 
     for pool_sqrt_price in events.pool_sqrt_prices {
         let ord = pool_sqrt_price.ordinal;
+        if price_outliers
+            .outliers
+            .iter()
+            .any(|outlier| outlier.ordinal == ord && outlier.pool_address == pool_sqrt_price.pool_address)
+        {
+            log::info!(
+                "skipping outlier price of pool {} at ordinal {ord}",
+                pool_sqrt_price.pool_address
+            );
+            continue;
+        }
+
         log::debug!(
             "handling pool price update - addr: {} price: {}",
             pool_sqrt_price.pool_address,
@@ -483,95 +799,183 @@ pub fn store_eth_prices(
         let pool = pools_store.must_get_last(format!("pool:{pool_address}"));
         let token0 = pool.token0.as_ref().unwrap();
         let token1 = pool.token1.as_ref().unwrap();
-        let token0_addr = &token0.address;
-        let token1_addr = &token1.address;
 
         token0.log();
         token1.log();
 
-        let bundle_eth_price_usd = price::get_eth_price_in_usd(&prices_store, ord);
+        let bundle_eth_price_usd =
+            price::get_eth_price_in_usd(&pool_prices, &pool_liquidities_store, ord, &config.pricing);
         log::info!("bundle_eth_price_usd: {}", bundle_eth_price_usd);
 
-        let token0_derived_eth_price: BigDecimal = price::find_eth_per_token(
-            ord,
-            &pool.address,
-            token0_addr,
-            &pools_store,
-            &pool_liquidities_store,
-            &tokens_whitelist_pools_store,
-            &total_native_amount_store,
-            &prices_store,
-        );
-        log::info!(format!(
-            "token 0 {token0_addr} derived eth price: {token0_derived_eth_price}"
-        ));
+        let bundle_sources = price::eth_price_sources(&pool_prices, &pool_liquidities_store, ord, &config.pricing)
+            .into_iter()
+            .map(|source| BundlePriceSource {
+                pool_address: source.pool,
+                eth_price_usd: match source.included {
+                    true => source.price.to_string(),
+                    false => BigDecimal::zero().to_string(),
+                },
+            })
+            .collect();
 
-        let token1_derived_eth_price: BigDecimal = price::find_eth_per_token(
-            ord,
-            &pool.address,
-            token1_addr,
-            &pools_store,
-            &pool_liquidities_store,
-            &tokens_whitelist_pools_store,
-            &total_native_amount_store,
-            &prices_store,
-        );
-        log::info!(format!(
-            "token 1 {token1_addr} derived eth price: {token1_derived_eth_price}"
-        ));
+        let token_price = |token: &Erc20Token| -> TokenPrice {
+            let token_addr = &token.address;
+            let eth_per_token = price::find_eth_per_token(
+                ord,
+                &pool.address,
+                token_addr,
+                &pools_store,
+                &pool_liquidities_store,
+                &tokens_whitelist_pools_store,
+                &token_pools_store,
+                &total_native_amount_store,
+                &pool_prices,
+                &config.pricing,
+            );
+            log::info!(format!(
+                "token {token_addr} derived eth price: {} ({} hops)",
+                eth_per_token.price, eth_per_token.hops
+            ));
+
+            TokenPrice {
+                token_address: token_addr.clone(),
+                derived_eth: eth_per_token.price.to_string(),
+                source_pool: eth_per_token.source_pool,
+                eth_locked: eth_per_token.eth_locked.to_string(),
+                hops: eth_per_token.hops,
+                block_number: clock.number,
+                ordinal: ord,
+            }
+        };
+
+        // quote tokens without an ETH price yet are left out
+        let quote_prices = config
+            .pricing
+            .quote_tokens
+            .iter()
+            .filter_map(|quote_token| {
+                let quote_eth_price = price::find_eth_per_token(
+                    ord,
+                    &pool.address,
+                    quote_token,
+                    &pools_store,
+                    &pool_liquidities_store,
+                    &tokens_whitelist_pools_store,
+                    &token_pools_store,
+                    &total_native_amount_store,
+                    &pool_prices,
+                    &config.pricing,
+                )
+                .price;
+                if quote_eth_price.eq(&BigDecimal::zero()) {
+                    return None;
+                }
+
+                Some(QuotePrice {
+                    quote_token: quote_token.clone(),
+                    derived_eth: quote_eth_price.to_string(),
+                })
+            })
+            .collect();
+
+        prices.push(DerivedPrice {
+            pool_address: pool_address.clone(),
+            ordinal: ord,
+            initialized: pool_sqrt_price.initialized,
+            bundle_eth_price_usd: bundle_eth_price_usd.to_string(),
+            bundle_sources,
+            token0: Some(token_price(token0)),
+            token1: Some(token_price(token1)),
+            quote_prices,
+        });
+    }
+
+    Ok(DerivedPrices { prices })
+}
+
+#[substreams::handlers::store]
+pub fn store_eth_prices(clock: Clock, derived_prices: DerivedPrices, output: StoreSetBigDecimal) {
+    let timestamp_seconds = clock.timestamp.unwrap().seconds;
+    let day_id = timestamp_seconds / 86400;
+    let hour_id = timestamp_seconds / 3600;
+    let prev_day_id = day_id - 1;
+    let prev_hour_id = hour_id - 1;
 
+    output.delete_prefix(0, &format!("TokenDayData:{prev_day_id}:"));
+    output.delete_prefix(0, &format!("TokenHourData:{prev_hour_id}:"));
+
+    for derived_price in derived_prices.prices {
+        let ord = derived_price.ordinal;
+        let bundle_eth_price_usd = BigDecimal::try_from(derived_price.bundle_eth_price_usd.as_str()).unwrap();
+
+        for source in &derived_price.bundle_sources {
+            output.set(
+                ord,
+                format!("bundle:{}", source.pool_address),
+                &BigDecimal::try_from(source.eth_price_usd.as_str()).unwrap(),
+            );
+        }
         output.set(ord, "bundle", &bundle_eth_price_usd);
-        output.set(
-            ord,
-            format!("token:{token0_addr}:dprice:eth"),
-            &token0_derived_eth_price,
-        );
-        output.set(
-            ord,
-            format!("token:{token1_addr}:dprice:eth"),
-            &token1_derived_eth_price,
-        );
 
-        let token0_price_usd = token0_derived_eth_price.clone().mul(bundle_eth_price_usd.clone());
-        let token1_price_usd = token1_derived_eth_price.clone().mul(bundle_eth_price_usd);
+        // the values in a quote token are derived from the ETH values with the ETH price of the quote token
+        for quote_price in &derived_price.quote_prices {
+            let quote_token = &quote_price.quote_token;
+            let derived_eth = BigDecimal::try_from(quote_price.derived_eth.as_str()).unwrap();
+            output.set(ord, format!("quote:{quote_token}"), &derived_eth);
+        }
 
-        log::info!("token0 price usd: {}", token0_price_usd);
-        log::info!("token1 price usd: {}", token1_price_usd);
+        for token_price in [derived_price.token0.unwrap(), derived_price.token1.unwrap()] {
+            let token_addr = &token_price.token_address;
+            let derived_eth_price = BigDecimal::try_from(token_price.derived_eth.as_str()).unwrap();
+            output.set(ord, format!("token:{token_addr}:dprice:eth"), &derived_eth_price);
 
-        // We only want to set the prices of TokenDayData and TokenHourData when
-        // the pool is post-initialized, not on the initialized event.
-        if pool_sqrt_price.initialized {
-            continue;
+            // We only want to set the prices of TokenDayData and TokenHourData when
+            // the pool is post-initialized, not on the initialized event.
+            if derived_price.initialized {
+                continue;
+            }
+
+            let token_price_usd = derived_eth_price.mul(bundle_eth_price_usd.clone());
+            log::info!("token {} price usd: {}", token_addr, token_price_usd);
+
+            output.set_many(
+                ord,
+                &vec![
+                    format!("TokenDayData:{day_id}:{token_addr}"),
+                    format!("TokenHourData:{hour_id}:{token_addr}"),
+                ],
+                &token_price_usd,
+            );
         }
+    }
+}
 
-        output.set_many(
-            ord,
-            &vec![
-                format!("TokenDayData:{day_id}:{token0_addr}"),
-                format!("TokenHourData:{hour_id}:{token0_addr}"),
-            ],
-            &token0_price_usd,
-        );
-        output.set_many(
-            ord,
-            &vec![
-                format!("TokenDayData:{day_id}:{token1_addr}"),
-                format!("TokenHourData:{hour_id}:{token1_addr}"),
-            ],
-            &token1_price_usd,
-        );
+#[substreams::handlers::store]
+pub fn store_token_price_sources(derived_prices: DerivedPrices, output: StoreSetProto<TokenPrice>) {
+    for derived_price in derived_prices.prices {
+        for token_price in [derived_price.token0.unwrap(), derived_price.token1.unwrap()] {
+            output.set(
+                token_price.ordinal,
+                format!("token:{}", token_price.token_address),
+                &token_price,
+            );
+        }
     }
 }
 
 #[substreams::handlers::store]
 pub fn store_swaps_volume(
+    params: String,
     clock: Clock,
     events: Events,
     store_pool: StoreGetProto<Pool>,
     store_total_tx_counts: StoreGetBigInt,
     store_eth_prices: StoreGetBigDecimal,
+    store_ticks_liquidities: StoreGetBigInt,
+    store_tick_bitmaps: StoreGetBigInt,
     output: StoreAddBigDecimal,
 ) {
+    let config = NetworkConfig::from_params(&params);
     let timestamp_seconds = clock.timestamp.unwrap().seconds;
     let day_id = timestamp_seconds / 86400;
     let hour_id = timestamp_seconds / 3600;
@@ -583,6 +987,8 @@ pub fn store_swaps_volume(
     output.delete_prefix(0, &format!("PoolHourData:{prev_hour_id}:"));
     output.delete_prefix(0, &format!("TokenDayData:{prev_day_id}:"));
     output.delete_prefix(0, &format!("TokenHourData:{prev_hour_id}:"));
+    output.delete_prefix(0, &format!("TickDayData:{prev_day_id}:"));
+    output.delete_prefix(0, &format!("TickHourData:{prev_hour_id}:"));
 
     for event in events.pool_events {
         let ord = event.log_ordinal;
@@ -624,7 +1030,8 @@ pub fn store_swaps_volume(
                 log::info!("token0_derived_eth_price {}", token0_derived_eth_price);
                 log::info!("token1_derived_eth_price {}", token1_derived_eth_price);
 
-                let amount0_abs = BigDecimal::try_from(swap.amount_0).unwrap().absolute();
+                let amount0 = BigDecimal::try_from(swap.amount_0).unwrap();
+                let amount0_abs = amount0.clone().absolute();
                 let amount1_abs = BigDecimal::try_from(swap.amount_1).unwrap().absolute();
 
                 log::info!("amount0_abs {}", amount0_abs);
@@ -638,6 +1045,7 @@ pub fn store_swaps_volume(
                     &token0_derived_eth_price,
                     &token1_derived_eth_price,
                     &eth_price_in_usd,
+                    &config.pricing,
                 );
 
                 log::info!("volumeAmounts.eth {}", volume_amounts.delta_tvl_eth);
@@ -669,6 +1077,16 @@ pub fn store_swaps_volume(
                 log::info!("fee_usd {}", fee_usd);
                 log::info!("fee_tier {}", fee_tier);
 
+                for quote_token in &config.pricing.quote_tokens {
+                    if let Some(quote_derived_eth) = store_eth_prices.get_at(ord, format!("quote:{quote_token}")) {
+                        output.add(
+                            ord,
+                            format!("PoolQuote:{pool_address}:{quote_token}:volume"),
+                            &math::safe_div(&volume_eth, &quote_derived_eth),
+                        );
+                    }
+                }
+
                 output.add_many(
                     ord,
                     &vec![
@@ -753,16 +1171,337 @@ pub fn store_swaps_volume(
                     &fee_usd,
                 );
                 output.add(ord, format!("factory:totalFeesETH"), &fee_eth);
+
+                // attribute the swap to the liquidity ranges it went through, by their lower initialized tick. The
+                // ticks crossed come from the storage changes of the swap, only the range below them is looked up
+                let crossed_ticks: Vec<(i32, i128)> = events
+                    .ticks_crossed
+                    .iter()
+                    .filter(|tick| tick.log_ordinal == ord && &tick.pool_address == pool_address)
+                    .map(|tick| {
+                        let liquidity_net = store_ticks_liquidities
+                            .get_at(ord, format!("tick:{pool_address}:{}:liquidityNet", tick.idx))
+                            .unwrap_or_default();
+                        (
+                            tick.idx.parse::<i32>().unwrap(),
+                            liquidity_net.to_string().parse::<i128>().unwrap(),
+                        )
+                    })
+                    .collect();
+                let lowest_tick = swap
+                    .tick_before
+                    .parse::<i32>()
+                    .unwrap()
+                    .min(swap.tick.parse::<i32>().unwrap());
+                let lowest_range_start = tick_bitmap::initialized_tick_at_or_below(
+                    lowest_tick,
+                    pool.tick_spacing,
+                    tick_ranges::MAX_BITMAP_WORD_LOOKUPS,
+                    |word_pos| {
+                        store_tick_bitmaps
+                            .get_at(ord, format!("pool:{pool_address}:tickBitmap:{word_pos}"))
+                            .map(|word| math::u256_from_signed(&word.to_string()))
+                    },
+                );
+                let ranges = tick_ranges::traversed_ranges(
+                    U256::from_dec_str(&swap.sqrt_price_before).unwrap(),
+                    U256::from_dec_str(&swap.sqrt_price).unwrap(),
+                    swap.liquidity.parse::<i128>().unwrap(),
+                    &crossed_ticks,
+                    lowest_range_start,
+                );
+                for (tick_idx, share0, share1) in ranges {
+                    // the amounts in USD follow the token swapped in
+                    let share = if amount0.gt(&BigDecimal::zero()) {
+                        share0.clone()
+                    } else {
+                        share1.clone()
+                    };
+                    output.add_many(
+                        ord,
+                        &vec![
+                            format!("tick:{pool_address}:{tick_idx}:volumeToken0"),
+                            format!("TickDayData:{day_id}:{pool_address}:{tick_idx}:volumeToken0"),
+                            format!("TickHourData:{hour_id}:{pool_address}:{tick_idx}:volumeToken0"),
+                        ],
+                        &amount0_abs.clone().mul(share0),
+                    );
+                    output.add_many(
+                        ord,
+                        &vec![
+                            format!("tick:{pool_address}:{tick_idx}:volumeToken1"),
+                            format!("TickDayData:{day_id}:{pool_address}:{tick_idx}:volumeToken1"),
+                            format!("TickHourData:{hour_id}:{pool_address}:{tick_idx}:volumeToken1"),
+                        ],
+                        &amount1_abs.clone().mul(share1),
+                    );
+                    output.add_many(
+                        ord,
+                        &vec![
+                            format!("tick:{pool_address}:{tick_idx}:volumeUSD"),
+                            format!("TickDayData:{day_id}:{pool_address}:{tick_idx}:volumeUSD"),
+                            format!("TickHourData:{hour_id}:{pool_address}:{tick_idx}:volumeUSD"),
+                        ],
+                        &volume_usd.clone().mul(share.clone()),
+                    );
+                    output.add(
+                        ord,
+                        format!("tick:{pool_address}:{tick_idx}:untrackedVolumeUSD"),
+                        &volume_usd_untracked.clone().mul(share.clone()),
+                    );
+                    output.add_many(
+                        ord,
+                        &vec![
+                            format!("tick:{pool_address}:{tick_idx}:feesUSD"),
+                            format!("TickDayData:{day_id}:{pool_address}:{tick_idx}:feesUSD"),
+                            format!("TickHourData:{hour_id}:{pool_address}:{tick_idx}:feesUSD"),
+                        ],
+                        &fee_usd.clone().mul(share),
+                    );
+                }
             }
             _ => {}
         }
     }
 }
 
+#[substreams::handlers::store]
+pub fn store_collected_fees(events: Events, store_eth_prices: StoreGetBigDecimal, output: StoreAddBigDecimal) {
+    for event in events.pool_events {
+        let ord = event.log_ordinal;
+        let pool_address = &event.pool_address;
+        let token0_addr = &event.token0;
+        let token1_addr = &event.token1;
+
+        // Like the subgraph, the collected amounts also contain the principal withdrawn by the burns
+        if let CollectEvent(collect) = event.r#type.unwrap() {
+            let amount0 = BigDecimal::try_from(collect.amount_0.as_str()).unwrap();
+            let amount1 = BigDecimal::try_from(collect.amount_1.as_str()).unwrap();
+
+            let token0_derived_eth_price = store_eth_prices
+                .get_at(ord, format!("token:{token0_addr}:dprice:eth"))
+                .unwrap_or_default();
+            let token1_derived_eth_price = store_eth_prices
+                .get_at(ord, format!("token:{token1_addr}:dprice:eth"))
+                .unwrap_or_default();
+            let bundle_eth_price = store_eth_prices.get_at(ord, "bundle").unwrap_or_default();
+            let amount_usd = utils::calculate_amount_usd(
+                &amount0,
+                &amount1,
+                &token0_derived_eth_price,
+                &token1_derived_eth_price,
+                &bundle_eth_price,
+            );
+
+            let tick_lower = &collect.tick_lower;
+            let tick_upper = &collect.tick_upper;
+            output.add_many(
+                ord,
+                &vec![
+                    format!("pool:{pool_address}:collectedFeesToken0"),
+                    format!("tick:{pool_address}:{tick_lower}:collectedFeesToken0"),
+                    format!("tick:{pool_address}:{tick_upper}:collectedFeesToken0"),
+                ],
+                &amount0,
+            );
+            output.add_many(
+                ord,
+                &vec![
+                    format!("pool:{pool_address}:collectedFeesToken1"),
+                    format!("tick:{pool_address}:{tick_lower}:collectedFeesToken1"),
+                    format!("tick:{pool_address}:{tick_upper}:collectedFeesToken1"),
+                ],
+                &amount1,
+            );
+            output.add_many(
+                ord,
+                &vec![
+                    format!("pool:{pool_address}:collectedFeesUSD"),
+                    format!("tick:{pool_address}:{tick_lower}:collectedFeesUSD"),
+                    format!("tick:{pool_address}:{tick_upper}:collectedFeesUSD"),
+                ],
+                &amount_usd,
+            );
+        }
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_position_withdrawn_less_collected(events: Events, output: StoreAddBigDecimal) {
+    for position in events.decrease_liquidity_positions {
+        let token_id = &position.token_id;
+        output.add(
+            position.log_ordinal,
+            format!("position:{token_id}:token0"),
+            &BigDecimal::try_from(position.withdrawn_token0.as_str()).unwrap(),
+        );
+        output.add(
+            position.log_ordinal,
+            format!("position:{token_id}:token1"),
+            &BigDecimal::try_from(position.withdrawn_token1.as_str()).unwrap(),
+        );
+    }
+
+    for position in events.collect_positions {
+        let token_id = &position.token_id;
+        output.add(
+            position.log_ordinal,
+            format!("position:{token_id}:token0"),
+            &BigDecimal::try_from(position.collected_fees_token0.as_str())
+                .unwrap()
+                .neg(),
+        );
+        output.add(
+            position.log_ordinal,
+            format!("position:{token_id}:token1"),
+            &BigDecimal::try_from(position.collected_fees_token1.as_str())
+                .unwrap()
+                .neg(),
+        );
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_position_collected_fees(
+    events: Events,
+    withdrawn_less_collected_store: StoreGetBigDecimal, /* store_position_withdrawn_less_collected */
+    output: StoreMaxBigDecimal,
+) {
+    // The position manager collects the fees and the withdrawn amounts together. The withdrawn amounts are
+    // deemed collected first, so the fees collected so far are the most the position ever collected beyond
+    // what it withdrew.
+    for position in events.collect_positions {
+        let ord = position.log_ordinal;
+        let token_id = &position.token_id;
+        for token_idx in [0, 1] {
+            let collected_beyond_withdrawn = withdrawn_less_collected_store
+                .get_at(ord, format!("position:{token_id}:token{token_idx}"))
+                .unwrap_or_default()
+                .neg();
+            let collected_fees = match collected_beyond_withdrawn.gt(&BigDecimal::zero()) {
+                true => collected_beyond_withdrawn,
+                false => BigDecimal::zero(),
+            };
+            output.max(
+                ord,
+                format!("position:{token_id}:collectedFeesToken{token_idx}"),
+                &collected_fees,
+            );
+        }
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_pool_fee_protocol(events: Events, store: StoreSetBigInt) {
+    for update in events.fee_protocol_updates {
+        let pool_address = &update.pool_address;
+        store.set(
+            update.log_ordinal,
+            format!("pool:{pool_address}:feeProtocol0"),
+            &BigInt::from(update.fee_protocol_0),
+        );
+        store.set(
+            update.log_ordinal,
+            format!("pool:{pool_address}:feeProtocol1"),
+            &BigInt::from(update.fee_protocol_1),
+        );
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_protocol_fees(events: Events, store_eth_prices: StoreGetBigDecimal, output: StoreAddBigDecimal) {
+    for accrued in events.protocol_fees_accrued {
+        let ord = accrued.log_ordinal;
+        let pool_address = &accrued.pool_address;
+        let amount0 = BigDecimal::try_from(accrued.amount_0.as_str()).unwrap();
+        let amount1 = BigDecimal::try_from(accrued.amount_1.as_str()).unwrap();
+        let amount_usd = protocol_fees_amount_usd(
+            ord,
+            &accrued.token0,
+            &accrued.token1,
+            &amount0,
+            &amount1,
+            &store_eth_prices,
+        );
+
+        output.add(ord, format!("pool:{pool_address}:protocolFeesAccruedToken0"), &amount0);
+        output.add(ord, format!("pool:{pool_address}:protocolFeesAccruedToken1"), &amount1);
+        output.add_many(
+            ord,
+            &vec![
+                format!("pool:{pool_address}:protocolFeesAccruedUSD"),
+                format!("factory:protocolFeesAccruedUSD"),
+            ],
+            &amount_usd,
+        );
+    }
+
+    for collect in events.collect_protocols {
+        let ord = collect.log_ordinal;
+        let pool_address = &collect.pool_address;
+        let amount0 = BigDecimal::try_from(collect.amount_0.as_str()).unwrap();
+        let amount1 = BigDecimal::try_from(collect.amount_1.as_str()).unwrap();
+        let amount_usd = protocol_fees_amount_usd(
+            ord,
+            &collect.token0,
+            &collect.token1,
+            &amount0,
+            &amount1,
+            &store_eth_prices,
+        );
+
+        output.add(
+            ord,
+            format!("pool:{pool_address}:protocolFeesCollectedToken0"),
+            &amount0,
+        );
+        output.add(
+            ord,
+            format!("pool:{pool_address}:protocolFeesCollectedToken1"),
+            &amount1,
+        );
+        output.add_many(
+            ord,
+            &vec![
+                format!("pool:{pool_address}:protocolFeesCollectedUSD"),
+                format!("factory:protocolFeesCollectedUSD"),
+            ],
+            &amount_usd,
+        );
+    }
+}
+
+fn protocol_fees_amount_usd(
+    ord: u64,
+    token0_addr: &String,
+    token1_addr: &String,
+    amount0: &BigDecimal,
+    amount1: &BigDecimal,
+    store_eth_prices: &StoreGetBigDecimal,
+) -> BigDecimal {
+    let token0_derived_eth_price = store_eth_prices
+        .get_at(ord, format!("token:{token0_addr}:dprice:eth"))
+        .unwrap_or_default();
+    let token1_derived_eth_price = store_eth_prices
+        .get_at(ord, format!("token:{token1_addr}:dprice:eth"))
+        .unwrap_or_default();
+    let bundle_eth_price = store_eth_prices.get_at(ord, "bundle").unwrap_or_default();
+    utils::calculate_amount_usd(
+        amount0,
+        amount1,
+        &token0_derived_eth_price,
+        &token1_derived_eth_price,
+        &bundle_eth_price,
+    )
+}
+
 #[substreams::handlers::store]
 pub fn store_token_tvl(events: Events, output: StoreAddBigDecimal) {
     for pool_event in events.pool_events {
-        let token_amounts = pool_event.get_amounts().unwrap();
+        let token_amounts = match pool_event.get_amounts() {
+            Some(token_amounts) => token_amounts,
+            None => continue,
+        };
         let pool_address = pool_event.pool_address.to_string();
         let token0_addr = pool_event.token0.to_string();
         let token1_addr = pool_event.token1.to_string();
@@ -790,6 +1529,7 @@ pub fn store_token_tvl(events: Events, output: StoreAddBigDecimal) {
 
 #[substreams::handlers::store]
 pub fn store_derived_tvl(
+    params: String,
     clock: Clock,
     events: Events,
     token_total_value_locked: StoreGetBigDecimal, /* store_token_tvl  */
@@ -797,6 +1537,7 @@ pub fn store_derived_tvl(
     eth_prices_store: StoreGetBigDecimal,
     output: StoreSetBigDecimal,
 ) {
+    let config = NetworkConfig::from_params(&params);
     let timestamp_seconds = clock.timestamp.unwrap().seconds;
     let day_id: i64 = timestamp_seconds / 86400;
     let hour_id: i64 = timestamp_seconds / 3600;
@@ -855,6 +1596,7 @@ pub fn store_derived_tvl(
             &token0_derive_eth,
             &token1_derive_eth,
             &eth_price_usd,
+            &config.pricing,
         );
         // let amounts_for_token = utils::get_adjusted_amounts(
         //     token0_addr,
@@ -921,6 +1663,18 @@ pub fn store_derived_tvl(
             format!("pool:{pool_address}:totalValueLockedUSDUntracked"),
             &amounts_in_pool.stable_usd_untracked,
         );
+
+        // the token values in ETH, for their values in the quote tokens
+        output.set(
+            ord,
+            format!("token:{token0_addr}:totalValueLockedETH"),
+            &tvl_for_token0.clone().mul(token0_derive_eth.clone()),
+        );
+        output.set(
+            ord,
+            format!("token:{token1_addr}:totalValueLockedETH"),
+            &tvl_for_token1.clone().mul(token1_derive_eth.clone()),
+        );
     }
 }
 
@@ -1050,6 +1804,54 @@ pub fn store_ticks_liquidities(clock: Clock, events: Events, output: StoreAddBig
     }
 }
 
+#[substreams::handlers::store]
+pub fn store_tick_bitmaps(events: Events, output: StoreSetBigInt) {
+    for update in events.tick_bitmap_updates {
+        let pool_address = &update.pool_address;
+        let word_pos = update.word_pos;
+        output.set(
+            update.log_ordinal,
+            format!("pool:{pool_address}:tickBitmap:{word_pos}"),
+            &BigInt::try_from(&update.new_value).unwrap(),
+        );
+    }
+}
+
+#[substreams::handlers::map]
+pub fn map_initialized_ticks(
+    params: String,
+    clock: Clock,
+    pools_store: StoreGetProto<Pool>,
+    tick_bitmaps_store: StoreGetBigInt,
+) -> Result<InitializedTicks, Error> {
+    let mut pools: Vec<PoolInitializedTicks> = vec![];
+    for pool_address in tick_bitmap::parse_pools(&params) {
+        let pool = match pools_store.get_last(format!("pool:{pool_address}")) {
+            Some(pool) if pool.tick_spacing > 0 => pool,
+            _ => continue,
+        };
+
+        // every word the pool can have is looked up, the words never written are empty
+        let mut ticks: Vec<i32> = vec![];
+        for word_pos in tick_bitmap::word_positions(pool.tick_spacing) {
+            if let Some(word) = tick_bitmaps_store.get_last(format!("pool:{pool_address}:tickBitmap:{word_pos}")) {
+                // the words are uint256 decoded as signed integers
+                let word = math::u256_from_signed(&word.to_string());
+                ticks.extend(tick_bitmap::initialized_ticks(word_pos, word, pool.tick_spacing));
+            }
+        }
+
+        pools.push(PoolInitializedTicks {
+            pool_address,
+            tick_spacing: pool.tick_spacing,
+            block_number: clock.number,
+            ticks,
+        });
+    }
+
+    Ok(InitializedTicks { pools })
+}
+
 #[substreams::handlers::store]
 pub fn store_positions(events: Events, output: StoreSetProto<PositionEvent>) {
     let mut positions_events: Vec<PositionEvent> = vec![];
@@ -1112,6 +1914,395 @@ pub fn store_positions(events: Events, output: StoreSetProto<PositionEvent>) {
     }
 }
 
+#[substreams::handlers::store]
+pub fn store_pool_positions(events: Events, output_append: StoreAppend<String>) {
+    // the positions of a pool are listed in the blocks of ticks their range covers, whether they are still open is
+    // read from their liquidity
+    for position in events.created_positions.iter() {
+        let pool = &position.pool;
+        let tick_lower: i32 = position.tick_lower.parse().unwrap();
+        let tick_upper: i32 = position.tick_upper.parse().unwrap();
+        for (level, index) in position_index::range_blocks(tick_lower, tick_upper) {
+            output_append.append(
+                position.log_ordinal,
+                format!("pool:{pool}:positions:{level}:{index}"),
+                position.token_id.clone(),
+            );
+        }
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_position_liquidities(events: Events, output: StoreSetBigInt) {
+    let mut liquidity_updates: Vec<(u64, &String, &String)> = vec![];
+    for position in events.increase_liquidity_positions.iter() {
+        if let Some(liquidity) = &position.position_liquidity {
+            liquidity_updates.push((position.log_ordinal, &position.token_id, liquidity));
+        }
+    }
+    for position in events.decrease_liquidity_positions.iter() {
+        if let Some(liquidity) = &position.position_liquidity {
+            liquidity_updates.push((position.log_ordinal, &position.token_id, liquidity));
+        }
+    }
+
+    liquidity_updates.sort_by(|x, y| x.0.cmp(&y.0));
+
+    for (ordinal, token_id, liquidity) in liquidity_updates {
+        output.set(
+            ordinal,
+            format!("position:{token_id}"),
+            &BigInt::try_from(liquidity).unwrap(),
+        );
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_fee_growth(events: Events, output: StoreSetBigInt) {
+    let mut updates: Vec<(u64, String, &String)> = vec![];
+    for update in events.fee_growth_global_updates.iter() {
+        let pool_address = &update.pool_address;
+        let token_idx = update.token_idx;
+        updates.push((
+            update.ordinal,
+            format!("pool:{pool_address}:feeGrowthGlobal{token_idx}X128"),
+            &update.new_value,
+        ));
+    }
+
+    let ticks_fee_growths = events
+        .ticks_updated
+        .iter()
+        .map(|tick| {
+            (
+                tick.log_ordinal,
+                &tick.pool_address,
+                &tick.idx,
+                [&tick.fee_growth_outside_0x_128, &tick.fee_growth_outside_1x_128],
+            )
+        })
+        .chain(events.ticks_crossed.iter().map(|tick| {
+            (
+                tick.log_ordinal,
+                &tick.pool_address,
+                &tick.idx,
+                [&tick.fee_growth_outside_0x_128, &tick.fee_growth_outside_1x_128],
+            )
+        }));
+    for (ordinal, pool_address, tick_idx, fee_growths_outside) in ticks_fee_growths {
+        for (token_idx, fee_growth_outside) in fee_growths_outside.into_iter().enumerate() {
+            // the fee growth outside is only known when it changed
+            if !fee_growth_outside.is_empty() {
+                updates.push((
+                    ordinal,
+                    format!("tick:{pool_address}:{tick_idx}:feeGrowthOutside{token_idx}X128"),
+                    fee_growth_outside,
+                ));
+            }
+        }
+    }
+
+    for position in events.created_positions.iter() {
+        push_position_fee_values(
+            &mut updates,
+            position.log_ordinal,
+            &position.token_id,
+            &[
+                ("feeGrowthInside0LastX128", &position.fee_growth_inside0_last_x128),
+                ("feeGrowthInside1LastX128", &position.fee_growth_inside1_last_x128),
+            ],
+        );
+    }
+    for position in events.increase_liquidity_positions.iter() {
+        push_position_fee_values(
+            &mut updates,
+            position.log_ordinal,
+            &position.token_id,
+            &[
+                ("feeGrowthInside0LastX128", &position.fee_growth_inside0_last_x128),
+                ("feeGrowthInside1LastX128", &position.fee_growth_inside1_last_x128),
+                ("tokensOwed0", &position.tokens_owed0),
+                ("tokensOwed1", &position.tokens_owed1),
+            ],
+        );
+    }
+    for position in events.decrease_liquidity_positions.iter() {
+        push_position_fee_values(
+            &mut updates,
+            position.log_ordinal,
+            &position.token_id,
+            &[
+                ("feeGrowthInside0LastX128", &position.fee_growth_inside0_last_x128),
+                ("feeGrowthInside1LastX128", &position.fee_growth_inside1_last_x128),
+                ("tokensOwed0", &position.tokens_owed0),
+                ("tokensOwed1", &position.tokens_owed1),
+            ],
+        );
+    }
+    for position in events.collect_positions.iter() {
+        push_position_fee_values(
+            &mut updates,
+            position.log_ordinal,
+            &position.token_id,
+            &[
+                ("feeGrowthInside0LastX128", &position.fee_growth_inside0_last_x128),
+                ("feeGrowthInside1LastX128", &position.fee_growth_inside1_last_x128),
+                ("tokensOwed0", &position.tokens_owed0),
+                ("tokensOwed1", &position.tokens_owed1),
+            ],
+        );
+    }
+
+    updates.sort_by(|x, y| x.0.cmp(&y.0));
+
+    for (ordinal, key, value) in updates {
+        output.set(ordinal, key, &BigInt::try_from(value).unwrap());
+    }
+}
+
+fn push_position_fee_values<'a>(
+    updates: &mut Vec<(u64, String, &'a String)>,
+    ordinal: u64,
+    token_id: &String,
+    values: &[(&str, &'a Option<String>)],
+) {
+    for &(field_name, value) in values {
+        if let Some(value) = value {
+            updates.push((ordinal, format!("position:{token_id}:{field_name}"), value));
+        }
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_raw_position_liquidities(events: Events, output: StoreAddBigInt) {
+    for position in events.raw_position_updates {
+        let id = position.id();
+        output.add(
+            position.log_ordinal,
+            format!("raw_position:{id}:liquidity"),
+            &BigInt::try_from(&position.liquidity_delta).unwrap(),
+        );
+    }
+}
+
+#[substreams::handlers::store]
+pub fn store_raw_position_amounts(events: Events, output: StoreAddBigDecimal) {
+    for position in events.raw_position_updates {
+        let id = position.id();
+        // every amount is added, even when zero, so the first event of a raw position creates all its keys
+        for (field_name, amount) in [
+            ("depositedToken0", &position.deposited_token0),
+            ("depositedToken1", &position.deposited_token1),
+            ("withdrawnToken0", &position.withdrawn_token0),
+            ("withdrawnToken1", &position.withdrawn_token1),
+            ("collectedToken0", &position.collected_token0),
+            ("collectedToken1", &position.collected_token1),
+        ] {
+            output.add(
+                position.log_ordinal,
+                format!("raw_position:{id}:{field_name}"),
+                &BigDecimal::try_from(amount.as_str()).unwrap(),
+            );
+        }
+    }
+}
+
+#[substreams::handlers::map]
+pub fn map_position_amounts(
+    clock: Clock,
+    events: Events,                                      /* map_extract_data_types */
+    pools_store: StoreGetProto<Pool>,                    /* store_pools_created */
+    positions_store: StoreGetProto<PositionEvent>,       /* store_positions */
+    pool_positions_store: StoreGetRaw,                   /* store_pool_positions */
+    position_liquidities_store: StoreGetBigInt,          /* store_position_liquidities */
+    pool_sqrt_price_store: StoreGetProto<PoolSqrtPrice>, /* store_pool_sqrt_price */
+    eth_prices_store: StoreGetBigDecimal,                /* store_eth_prices */
+    fee_growth_store: StoreGetBigInt,                    /* store_fee_growth */
+    withdrawn_less_collected_store: StoreGetBigDecimal,  /* store_position_withdrawn_less_collected */
+    collected_fees_store: StoreGetBigDecimal,            /* store_position_collected_fees */
+) -> Result<PositionAmounts, Error> {
+    // the positions with an event, and the positions of the pools whose price moved whose range contains the tick
+    // before or after a swap or one of the ticks it crossed, the only ones the swap moved the price within, or the
+    // current tick of a flash, which grows the fees without moving the price
+    let mut token_ids: Vec<String> = vec![];
+    for position in events.increase_liquidity_positions.iter() {
+        token_ids.push(position.token_id.clone());
+    }
+    for position in events.decrease_liquidity_positions.iter() {
+        token_ids.push(position.token_id.clone());
+    }
+    for position in events.collect_positions.iter() {
+        token_ids.push(position.token_id.clone());
+    }
+    for position in events.transfer_positions.iter() {
+        token_ids.push(position.token_id.clone());
+    }
+
+    let mut pool_ticks: BTreeSet<(&String, i32)> = BTreeSet::new();
+    for event in events.pool_events.iter() {
+        if let Some(SwapEvent(swap)) = &event.r#type {
+            pool_ticks.insert((&event.pool_address, swap.tick_before.parse().unwrap()));
+            pool_ticks.insert((&event.pool_address, swap.tick.parse().unwrap()));
+        }
+    }
+    for tick in events.ticks_crossed.iter() {
+        pool_ticks.insert((&tick.pool_address, tick.idx.parse().unwrap()));
+    }
+    for flash in events.flashes.iter() {
+        if let Some(sqrt_price) = pool_sqrt_price_store.get_last(format!("pool:{}", flash.pool_address)) {
+            pool_ticks.insert((&flash.pool_address, sqrt_price.tick.parse().unwrap()));
+        }
+    }
+    // ordered, the positions are valued in a deterministic order
+    let mut pool_blocks: BTreeSet<(&String, (u32, i32))> = BTreeSet::new();
+    for (pool_address, tick) in pool_ticks {
+        for block in position_index::tick_blocks(tick) {
+            pool_blocks.insert((pool_address, block));
+        }
+    }
+    for (pool_address, (level, index)) in pool_blocks {
+        if let Some(bytes) = pool_positions_store.get_last(format!("pool:{pool_address}:positions:{level}:{index}")) {
+            let pool_positions = String::from_utf8(bytes.to_vec()).unwrap();
+            for token_id in pool_positions.split(";").filter(|token_id| !token_id.is_empty()) {
+                token_ids.push(token_id.to_string());
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    token_ids.retain(|token_id| seen.insert(token_id.clone()));
+
+    // fee growths are uint256 decoded as signed integers, missing ones were never set
+    let fee_growth = |key: String| match fee_growth_store.get_last(key) {
+        Some(value) => math::u256_from_signed(&value.to_string()),
+        None => U256::zero(),
+    };
+    let to_decimal = |amount: U256, decimals: u64| BigInt::try_from(&amount.to_string()).unwrap().to_decimal(decimals);
+
+    let bundle_eth_price = eth_prices_store.get_last("bundle").unwrap_or_default();
+    let mut amounts = vec![];
+    for token_id in token_ids {
+        let position = match positions_store.get_last(format!("position_created:{token_id}")) {
+            Some(PositionEvent {
+                r#type: Some(CreatedPosition(position)),
+            }) => position,
+            _ => continue,
+        };
+        let liquidity = match position_liquidities_store.get_last(format!("position:{token_id}")) {
+            None => continue,
+            Some(liquidity) => liquidity,
+        };
+        // a closed position is only valued by the decrease closing it, which zeroes its amounts
+        if liquidity.eq(&BigInt::zero())
+            && !events
+                .decrease_liquidity_positions
+                .iter()
+                .any(|position| position.token_id == token_id)
+        {
+            continue;
+        }
+        let pool_address = &position.pool;
+        let (pool, sqrt_price) = match (
+            pools_store.get_last(format!("pool:{pool_address}")),
+            pool_sqrt_price_store.get_last(format!("pool:{pool_address}")),
+        ) {
+            (Some(pool), Some(sqrt_price)) => (pool, sqrt_price),
+            _ => continue,
+        };
+        let token0 = pool.token0_ref();
+        let token1 = pool.token1_ref();
+        let tick_lower: i32 = position.tick_lower.parse().unwrap();
+        let tick_upper: i32 = position.tick_upper.parse().unwrap();
+        let tick_current: i32 = sqrt_price.tick.parse().unwrap();
+        let position_liquidity = U256::from_dec_str(&liquidity.to_string()).unwrap();
+
+        let (amount0, amount1) = math::get_amounts_for_liquidity(
+            U256::from_dec_str(&sqrt_price.sqrt_price).unwrap(),
+            math::get_sqrt_ratio_at_tick(tick_lower),
+            math::get_sqrt_ratio_at_tick(tick_upper),
+            position_liquidity,
+        );
+        let amount0 = to_decimal(amount0, token0.decimals);
+        let amount1 = to_decimal(amount1, token1.decimals);
+
+        // The fees earned since the last update of the position, and the fees earned before, which the position
+        // manager credited to its tokens owed along with the withdrawn liquidity. The withdrawn amounts not
+        // collected yet are the withdrawn amounts less the collected ones, plus the collected fees.
+        let mut uncollected_fees = vec![];
+        for (token_idx, decimals) in [(0, token0.decimals), (1, token1.decimals)] {
+            let fee_growth_inside = math::get_fee_growth_inside(
+                tick_lower,
+                tick_upper,
+                tick_current,
+                fee_growth(format!("pool:{pool_address}:feeGrowthGlobal{token_idx}X128")),
+                fee_growth(format!(
+                    "tick:{pool_address}:{tick_lower}:feeGrowthOutside{token_idx}X128"
+                )),
+                fee_growth(format!(
+                    "tick:{pool_address}:{tick_upper}:feeGrowthOutside{token_idx}X128"
+                )),
+            );
+            let fee_growth_inside_last = fee_growth(format!("position:{token_id}:feeGrowthInside{token_idx}LastX128"));
+            let fees_earned = math::get_fees_earned(fee_growth_inside, fee_growth_inside_last, position_liquidity);
+
+            let tokens_owed = fee_growth_store
+                .get_last(format!("position:{token_id}:tokensOwed{token_idx}"))
+                .unwrap_or_default()
+                .to_decimal(decimals);
+            let uncollected_withdrawn = withdrawn_less_collected_store
+                .get_last(format!("position:{token_id}:token{token_idx}"))
+                .unwrap_or_default()
+                + collected_fees_store
+                    .get_last(format!("position:{token_id}:collectedFeesToken{token_idx}"))
+                    .unwrap_or_default();
+            let owed_fees = tokens_owed - uncollected_withdrawn;
+            let owed_fees = match owed_fees.gt(&BigDecimal::zero()) {
+                true => owed_fees,
+                false => BigDecimal::zero(),
+            };
+
+            uncollected_fees.push(owed_fees + to_decimal(fees_earned, decimals));
+        }
+
+        let token0_derived_eth_price = eth_prices_store
+            .get_last(format!("token:{}:dprice:eth", token0.address))
+            .unwrap_or_default();
+        let token1_derived_eth_price = eth_prices_store
+            .get_last(format!("token:{}:dprice:eth", token1.address))
+            .unwrap_or_default();
+        let amount_usd = utils::calculate_amount_usd(
+            &amount0,
+            &amount1,
+            &token0_derived_eth_price,
+            &token1_derived_eth_price,
+            &bundle_eth_price,
+        );
+        let uncollected_fees_usd = utils::calculate_amount_usd(
+            &uncollected_fees[0],
+            &uncollected_fees[1],
+            &token0_derived_eth_price,
+            &token1_derived_eth_price,
+            &bundle_eth_price,
+        );
+
+        amounts.push(PositionAmount {
+            position: token_id,
+            pool: pool_address.clone(),
+            liquidity: liquidity.to_string(),
+            sqrt_price: sqrt_price.sqrt_price,
+            amount0: amount0.to_string(),
+            amount1: amount1.to_string(),
+            amount_usd: amount_usd.to_string(),
+            block_number: clock.number,
+            uncollected_fees_token0: uncollected_fees[0].to_string(),
+            uncollected_fees_token1: uncollected_fees[1].to_string(),
+            uncollected_fees_usd: uncollected_fees_usd.to_string(),
+        });
+    }
+
+    Ok(PositionAmounts { amounts })
+}
+
 #[substreams::handlers::store]
 pub fn store_min_windows(
     clock: Clock,
@@ -1236,6 +2427,7 @@ pub fn store_max_windows(
 
 #[substreams::handlers::map]
 pub fn graph_out(
+    params: String,
     clock: Clock,
     pool_count_deltas: Deltas<DeltaBigInt>,              /* store_pool_count */
     tx_count_deltas: Deltas<DeltaBigInt>,                /* store_total_tx_counts deltas */
@@ -1259,36 +2451,62 @@ pub fn graph_out(
     store_positions: StoreGetProto<PositionEvent>,       /* store_positions */
     min_windows_deltas: Deltas<DeltaBigDecimal>,         /* store_min_windows */
     max_windows_deltas: Deltas<DeltaBigDecimal>,         /* store_max_windows */
+    collected_fees_deltas: Deltas<DeltaBigDecimal>,      /* store_collected_fees */
+    store_position_collected_fees: StoreGetBigDecimal,   /* store_position_collected_fees */
+    fee_protocol_deltas: Deltas<DeltaBigInt>,            /* store_pool_fee_protocol */
+    protocol_fees_deltas: Deltas<DeltaBigDecimal>,       /* store_protocol_fees */
+    factory_events: FactoryEvents,                       /* map_factory_events */
+    store_ticks_liquidities: StoreGetBigInt,             /* store_ticks_liquidities */
+    store_swaps_volume: StoreGetBigDecimal,              /* store_swaps_volume */
+    price_source_deltas: Deltas<DeltaProto<TokenPrice>>, /* store_token_price_sources */
+    price_outliers: PriceOutliers,                       /* map_price_outliers */
+    position_amounts: PositionAmounts,                   /* map_position_amounts */
+    raw_position_amounts_deltas: Deltas<DeltaBigDecimal>, /* store_raw_position_amounts */
+    raw_position_liquidities_deltas: Deltas<DeltaBigInt>, /* store_raw_position_liquidities */
+    position_collected_fees_deltas: Deltas<DeltaBigDecimal>, /* store_position_collected_fees */
+    store_fee_growth: StoreGetBigInt,                    /* store_fee_growth */
+    store_derived_tvl: StoreGetBigDecimal,               /* store_derived_tvl */
 ) -> Result<EntityChanges, Error> {
+    let config = NetworkConfig::from_params(&params);
     let mut tables = Tables::new();
     let timestamp = clock.timestamp.unwrap().seconds;
 
-    if clock.number == 12369621 {
-        // FIXME: Hard-coded start block, how could we pull that from the manifest?
-        // FIXME: ideally taken from the params of the module
-        db::factory_created_factory_entity_change(&mut tables);
+    let factory_id = config.factory_id();
+
+    if clock.number == config.start_block {
+        db::factory_created_factory_entity_change(&mut tables, &factory_id);
         db::created_bundle_entity_change(&mut tables);
     }
 
     // Bundle
     db::bundle_store_eth_price_usd_bundle_entity_change(&mut tables, &derived_eth_prices_deltas);
+    db::bundle_price_sources_entity_change(&mut tables, &derived_eth_prices_deltas);
 
     // Factory:
-    db::pool_created_factory_entity_change(&mut tables, &pool_count_deltas);
-    db::tx_count_factory_entity_change(&mut tables, &tx_count_deltas);
-    db::swap_volume_factory_entity_change(&mut tables, &swaps_volume_deltas);
-    db::tvl_factory_entity_change(&mut tables, &derived_factory_tvl_deltas);
+    db::pool_created_factory_entity_change(&mut tables, &factory_id, &pool_count_deltas);
+    db::tx_count_factory_entity_change(&mut tables, &factory_id, &tx_count_deltas);
+    db::swap_volume_factory_entity_change(&mut tables, &factory_id, &swaps_volume_deltas);
+    db::tvl_factory_entity_change(&mut tables, &factory_id, &derived_factory_tvl_deltas);
+    db::protocol_fees_factory_entity_change(&mut tables, &factory_id, &protocol_fees_deltas);
+    db::owner_changed_factory_entity_change(&mut tables, &factory_id, &factory_events);
+
+    // FeeTier:
+    db::fee_amount_enabled_fee_tier_entity_change(&mut tables, &factory_id, &factory_events);
 
     // Pool:
     db::pools_created_pool_entity_changes(&mut tables, &pools_created);
     db::sqrt_price_and_tick_pool_entity_change(&mut tables, &pool_sqrt_price_deltas);
     db::liquidities_pool_entity_change(&mut tables, &pool_liquidities_store_deltas);
     db::fee_growth_global_pool_entity_change(&mut tables, &events.fee_growth_global_updates);
+    db::oracle_pool_entity_change(&mut tables, &events.pool_oracle_updates);
     db::total_value_locked_pool_entity_change(&mut tables, &derived_tvl_deltas);
     db::total_value_locked_by_token_pool_entity_change(&mut tables, &token_tvl_deltas);
     db::price_pool_entity_change(&mut tables, &price_deltas);
     db::tx_count_pool_entity_change(&mut tables, &tx_count_deltas);
     db::swap_volume_pool_entity_change(&mut tables, &swaps_volume_deltas);
+    db::collected_fees_pool_entity_change(&mut tables, &collected_fees_deltas);
+    db::fee_protocol_pool_entity_change(&mut tables, &fee_protocol_deltas);
+    db::protocol_fees_pool_entity_change(&mut tables, &protocol_fees_deltas);
 
     // Tokens:
     db::tokens_created_token_entity_changes(&mut tables, &pools_created, tokens_store);
@@ -1297,17 +2515,38 @@ pub fn graph_out(
     db::total_value_locked_by_token_token_entity_change(&mut tables, &token_tvl_deltas);
     db::total_value_locked_usd_token_entity_change(&mut tables, &derived_tvl_deltas);
     db::derived_eth_prices_token_entity_change(&mut tables, &derived_eth_prices_deltas);
+    db::price_sources_token_entity_change(&mut tables, &price_source_deltas);
+    db::quote_entity_change(
+        &mut tables,
+        &config.pricing.quote_tokens,
+        &derived_eth_prices_deltas,
+        &derived_tvl_deltas,
+        &swaps_volume_deltas,
+        &store_eth_prices,
+        &store_derived_tvl,
+        &store_swaps_volume,
+    );
     db::whitelist_token_entity_change(&mut tables, tokens_whitelist_pools_deltas);
 
     // Tick:
-    db::create_tick_entity_change(&mut tables, &events.ticks_created);
-    db::update_tick_entity_change(&mut tables, &events.ticks_updated);
-    db::liquidities_tick_entity_change(&mut tables, &ticks_liquidities_deltas);
+    db::create_tick_entity_change(&mut tables, &events.ticks_created, &ticks_liquidities_deltas);
+    db::update_tick_entity_change(&mut tables, &events.ticks_updated, &events.ticks_crossed);
+    reconcile_tick_liquidities(&events.ticks_updated, &store_ticks_liquidities);
+    db::swap_volume_tick_entity_change(&mut tables, &swaps_volume_deltas);
+    db::collected_fees_tick_entity_change(&mut tables, &collected_fees_deltas);
 
     // Tick Day/Hour data
-    // db::create_entity_tick_windows(&mut tables, &events.ticks_created);
-    // db::update_tick_windows(&mut tables, &events.ticks_updated);
-    // db::liquidities_tick_windows(&mut tables, &ticks_liquidities_deltas);
+    db::tick_windows_entity_change(
+        &mut tables,
+        timestamp,
+        &events.ticks_updated,
+        &events.ticks_crossed,
+        &ticks_liquidities_deltas,
+        &swaps_volume_deltas,
+        &store_ticks_liquidities,
+        &store_swaps_volume,
+        &store_fee_growth,
+    );
 
     // Position:
     // TODO: validate all the positions here
@@ -1316,6 +2555,8 @@ pub fn graph_out(
     db::decrease_liquidity_position_entity_change(&mut tables, &events.decrease_liquidity_positions);
     db::collect_position_entity_change(&mut tables, &events.collect_positions);
     db::transfer_position_entity_change(&mut tables, &events.transfer_positions);
+    db::collected_fees_position_entity_change(&mut tables, &position_collected_fees_deltas);
+    db::amounts_position_entity_change(&mut tables, &position_amounts);
 
     // PositionSnapshot:
     // TODO: validate all the snapshot positions here
@@ -1325,30 +2566,52 @@ pub fn graph_out(
         clock.number,
         &events.increase_liquidity_positions,
         &store_positions,
+        &store_position_collected_fees,
     );
     db::decrease_liquidity_snapshot_position_entity_change(
         &mut tables,
         clock.number,
         &events.decrease_liquidity_positions,
         &store_positions,
+        &store_position_collected_fees,
+    );
+    db::collect_snapshot_position_entity_change(
+        &mut tables,
+        clock.number,
+        &events.collect_positions,
+        &store_positions,
+        &store_position_collected_fees,
     );
-    db::collect_snapshot_position_entity_change(&mut tables, clock.number, &events.collect_positions, &store_positions);
     db::transfer_snapshot_position_entity_change(
         &mut tables,
         clock.number,
         &events.transfer_positions,
         &store_positions,
+        &store_position_collected_fees,
     );
+    db::uncollected_fees_snapshot_position_entity_change(&mut tables, clock.number, &events, &position_amounts);
+
+    // RawPosition:
+    db::raw_position_create_entity_change(&mut tables, &events.raw_position_updates, &raw_position_amounts_deltas);
+    db::raw_position_update_entity_change(&mut tables, &events.raw_position_updates);
+    db::liquidities_raw_position_entity_change(&mut tables, &raw_position_liquidities_deltas);
+    db::amounts_raw_position_entity_change(&mut tables, &raw_position_amounts_deltas);
 
     // Transaction:
     db::transaction_entity_change(&mut tables, &events.transactions);
 
     // Swap, Mint, Burn:
-    db::swaps_mints_burns_created_entity_change(&mut tables, &events.pool_events, tx_count_store, store_eth_prices);
+    db::swaps_mints_burns_created_entity_change(
+        &mut tables,
+        &events.pool_events,
+        &tx_count_store,
+        &store_eth_prices,
+        &price_outliers,
+        &config.pricing,
+    );
 
-    // Flashes:
-    // TODO: should we implement flashes entity change - UNISWAP has not done this part
-    // db::flashes_update_pool_fee_entity_change(&mut tables, events.flashes);
+    // Flashes: the pool fee growth is updated via `fee_growth_global_updates`
+    db::flashes_created_entity_change(&mut tables, &events.flashes, &store_eth_prices);
 
     // Uniswap day data:
     db::uniswap_day_data_create(&mut tables, &tx_count_deltas);
@@ -1391,3 +2654,101 @@ pub fn graph_out(
 
     Ok(tables.to_entity_changes())
 }
+
+// The tick liquidities decoded from the storage are the ones written to the entities, the ones added up from the
+// mints and burns amounts by `store_ticks_liquidities` should always match them at the end of the block
+fn reconcile_tick_liquidities(ticks_updated: &Vec<events::TickUpdated>, store_ticks_liquidities: &StoreGetBigInt) {
+    let mismatches = tick_liquidity_mismatches(ticks_updated, |key| {
+        store_ticks_liquidities.get_last(key).unwrap_or(BigInt::zero())
+    });
+    for (key, decoded_value, stored_value) in mismatches {
+        log::info!(
+            "tick liquidity mismatch on {}: decoded {} but the mints and burns add up to {}",
+            key,
+            decoded_value,
+            stored_value
+        );
+    }
+}
+
+/// The last decoded liquidities of the block which differ from the stored ones, with their key, decoded value and
+/// stored value
+fn tick_liquidity_mismatches<F>(ticks_updated: &[events::TickUpdated], stored_value: F) -> Vec<(String, String, BigInt)>
+where
+    F: Fn(&String) -> BigInt,
+{
+    let mut ticks_updated: Vec<&events::TickUpdated> = ticks_updated.iter().collect();
+    ticks_updated.sort_by_key(|tick| tick.log_ordinal);
+
+    let mut decoded_values: Vec<(String, &String)> = vec![];
+    for tick in ticks_updated {
+        for (field_name, value) in [
+            ("liquidityGross", &tick.liquidity_gross),
+            ("liquidityNet", &tick.liquidity_net),
+        ] {
+            if value.is_empty() {
+                continue;
+            }
+            let key = format!("tick:{}:{}:{field_name}", tick.pool_address, tick.idx);
+            decoded_values.retain(|(decoded_key, _)| decoded_key != &key);
+            decoded_values.push((key, value));
+        }
+    }
+
+    decoded_values
+        .into_iter()
+        .filter_map(|(key, decoded_value)| {
+            let stored_value = stored_value(&key);
+            match stored_value.to_string() == *decoded_value {
+                true => None,
+                false => Some((key, decoded_value.clone(), stored_value)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pb::uniswap::events::TickUpdated;
+    use crate::tick_liquidity_mismatches;
+    use substreams::scalar::BigInt;
+
+    fn tick_updated(log_ordinal: u64, idx: &str, liquidity_gross: &str, liquidity_net: &str) -> TickUpdated {
+        TickUpdated {
+            pool_address: "8ad599c3a0ff1de082011efddc58f1908eb6e6d8".to_string(),
+            idx: idx.to_string(),
+            log_ordinal,
+            liquidity_gross: liquidity_gross.to_string(),
+            liquidity_net: liquidity_net.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tick_liquidities_reconciled() {
+        let ticks_updated = vec![
+            tick_updated(2, "60", "100", "-100"),
+            tick_updated(1, "-60", "100", "100"),
+        ];
+        let stored = |key: &String| match key.ends_with(":liquidityNet") && key.contains(":60:") {
+            true => BigInt::from(-100),
+            false => BigInt::from(100),
+        };
+        assert!(tick_liquidity_mismatches(&ticks_updated, stored).is_empty());
+    }
+
+    #[test]
+    fn tick_liquidity_mismatch() {
+        // the last decoded value of the block is the one reconciled
+        let ticks_updated = vec![tick_updated(3, "60", "250", ""), tick_updated(1, "60", "100", "")];
+        let mismatches = tick_liquidity_mismatches(&ticks_updated, |_| BigInt::from(100));
+        assert_eq!(
+            vec![(
+                "tick:8ad599c3a0ff1de082011efddc58f1908eb6e6d8:60:liquidityGross".to_string(),
+                "250".to_string(),
+                BigInt::from(100)
+            )],
+            mismatches
+        );
+    }
+}
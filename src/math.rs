@@ -1,66 +1,293 @@
-use crate::ticks_idx::ONE_POINT_0001;
-use std::ops::{Div, Mul};
+use ethabi::ethereum_types::{U256, U512};
+use num_bigint::{BigInt as SignedInt, BigUint};
+use num_traits::ToPrimitive;
+use std::ops::Div;
+use std::str::FromStr;
 use substreams::scalar::BigDecimal;
 
-pub fn compute_price_from_tick_idx(desired_tick_idx: i32) -> BigDecimal {
-    if desired_tick_idx == 0 {
-        return BigDecimal::one();
+/// Lowest tick that may be passed to `get_sqrt_ratio_at_tick`, `log_1.0001(2^-128)`
+pub const MIN_TICK: i32 = -887272;
+/// Highest tick that may be passed to `get_sqrt_ratio_at_tick`, `log_1.0001(2^128)`
+pub const MAX_TICK: i32 = -MIN_TICK;
+
+/// `get_sqrt_ratio_at_tick(MIN_TICK)`
+pub const MIN_SQRT_RATIO: U256 = U256([4295128739, 0, 0, 0]);
+/// `get_sqrt_ratio_at_tick(MAX_TICK)`
+pub const MAX_SQRT_RATIO: U256 = U256([0x5d951d5263988d26, 0xefd1fc6a50648849, 0xfffd8963, 0]);
+
+/// Significant digits kept on the prices, the precision of graph-node's `BigDecimal`
+pub const PRICE_PRECISION: u64 = 34;
+
+/// Q128.128 value of `1 / sqrt(1.0001)`, for the bit 0 of the absolute tick
+const SQRT_RATIO_FACTOR_0: U256 = U256([0xaa2d162d1a594001, 0xfffcb933bd6fad37, 0, 0]);
+
+/// Q128.128 values of `1 / sqrt(1.0001)^(2^i)`, for the bits 1 to 19 of the absolute tick
+const SQRT_RATIO_FACTORS: [(u32, U256); 19] = [
+    (0x2, U256([0x59a46990580e213a, 0xfff97272373d4132, 0, 0])),
+    (0x4, U256([0xef12357cf3c7fdcc, 0xfff2e50f5f656932, 0, 0])),
+    (0x8, U256([0x1c3624eaa0941cd0, 0xffe5caca7e10e4e6, 0, 0])),
+    (0x10, U256([0xc9db58835c926644, 0xffcb9843d60f6159, 0, 0])),
+    (0x20, U256([0x472e6896dfb254c0, 0xff973b41fa98c081, 0, 0])),
+    (0x40, U256([0x43ec78b326b52861, 0xff2ea16466c96a38, 0, 0])),
+    (0x80, U256([0x11c461f1969c3053, 0xfe5dee046a99a2a8, 0, 0])),
+    (0x100, U256([0xdcffc83b479aa3a4, 0xfcbe86c7900a88ae, 0, 0])),
+    (0x200, U256([0x6f2b074cf7815e54, 0xf987a7253ac41317, 0, 0])),
+    (0x400, U256([0x940c7a398e4b70f3, 0xf3392b0822b70005, 0, 0])),
+    (0x800, U256([0x43b29c7fa6e889d9, 0xe7159475a2c29b74, 0, 0])),
+    (0x1000, U256([0x845ad8f792aa5825, 0xd097f3bdfd2022b8, 0, 0])),
+    (0x2000, U256([0x8a65dc1f90e061e5, 0xa9f746462d870fdf, 0, 0])),
+    (0x4000, U256([0x90bb3df62baf32f7, 0x70d869a156d2a1b8, 0, 0])),
+    (0x8000, U256([0x81231505542fcfa6, 0x31be135f97d08fd9, 0, 0])),
+    (0x10000, U256([0xc677de54f3e99bc9, 0x9aa508b5b7a84e1, 0, 0])),
+    (0x20000, U256([0x6699c329225ee604, 0x5d6af8dedb8119, 0, 0])),
+    (0x40000, U256([0x1ea926041bedfe98, 0x2216e584f5fa, 0, 0])),
+    (0x80000, U256([0x91f7dc42444e8fa2, 0x48a1703, 0, 0])),
+];
+
+/// Port of `TickMath.getSqrtRatioAtTick`: the Q64.96 sqrt price `sqrt(1.0001^tick) * 2^96`, bit for bit
+/// what the pool contract computes
+pub fn get_sqrt_ratio_at_tick(tick: i32) -> U256 {
+    if tick < MIN_TICK || tick > MAX_TICK {
+        panic!("tick {} out of range [{}, {}]", tick, MIN_TICK, MAX_TICK);
     }
 
-    let desired_tick_idx_abs = desired_tick_idx.abs();
-    let base_abs = desired_tick_idx_abs - (desired_tick_idx_abs % 1000);
-    let ratio = BigDecimal::try_from(1.0001).unwrap().with_prec(100);
-    let mut val = BigDecimal::try_from(*ONE_POINT_0001.get(&base_abs).unwrap()).unwrap();
+    let abs_tick = tick.unsigned_abs();
+    let mut ratio = if abs_tick & 0x1 != 0 {
+        SQRT_RATIO_FACTOR_0
+    } else {
+        U256::one() << 128
+    };
+    for (bit, factor) in SQRT_RATIO_FACTORS {
+        if abs_tick & bit != 0 {
+            ratio = (ratio * factor) >> 128;
+        }
+    }
 
-    let mut idx = base_abs;
-    while idx < desired_tick_idx_abs {
-        val = val.mul(ratio.clone()).with_prec(100);
-        idx += 1;
+    if tick > 0 {
+        ratio = U256::max_value() / ratio;
     }
 
-    if desired_tick_idx.lt(&0) {
-        val = safe_div(&BigDecimal::one(), &val).with_prec(100);
+    // Q128.128 to Q64.96, rounding up so that get_tick_at_sqrt_ratio of the result gives back the tick
+    let round_up = if ratio.low_u32() != 0 {
+        U256::one()
+    } else {
+        U256::zero()
+    };
+    (ratio >> 32) + round_up
+}
+
+/// Port of `TickMath.getTickAtSqrtRatio`: the greatest tick whose sqrt ratio is lower than or equal to
+/// `sqrt_price_x96`
+pub fn get_tick_at_sqrt_ratio(sqrt_price_x96: U256) -> i32 {
+    if sqrt_price_x96 < MIN_SQRT_RATIO || sqrt_price_x96 >= MAX_SQRT_RATIO {
+        panic!("sqrt price {} out of range", sqrt_price_x96);
     }
 
-    return val.with_prec(100);
+    let ratio = sqrt_price_x96 << 32;
+    let msb = ratio.bits() - 1;
+    let mut r = if msb >= 128 {
+        ratio >> (msb - 127)
+    } else {
+        ratio << (127 - msb)
+    };
+
+    // integer part of log_2(ratio) as a Q64.64, then 14 bits of the fractional part
+    let mut log_2 = SignedInt::from(msb as i64 - 128) << 64;
+    for i in (50..=63).rev() {
+        r = (r * r) >> 127;
+        let f = (r >> 128).low_u32() as usize;
+        log_2 |= SignedInt::from(f) << i;
+        r = r >> f;
+    }
+
+    // log_sqrt(1.0001)(ratio) as a Q128.128, the error bounds below come from the contract
+    let log_sqrt_10001 = log_2 * SignedInt::from(255738958999603826347141u128);
+    let tick_low: SignedInt =
+        (&log_sqrt_10001 - SignedInt::from_str("3402992956809132418596140100660247210").unwrap()) >> 128;
+    let tick_high: SignedInt =
+        (&log_sqrt_10001 + SignedInt::from_str("291339464771989622907027621153398088495").unwrap()) >> 128;
+    let tick_low = tick_low.to_i32().unwrap();
+    let tick_high = tick_high.to_i32().unwrap();
+
+    if tick_low == tick_high {
+        tick_low
+    } else if get_sqrt_ratio_at_tick(tick_high) <= sqrt_price_x96 {
+        tick_high
+    } else {
+        tick_low
+    }
 }
 
-pub fn safe_div(amount0: &BigDecimal, amount1: &BigDecimal) -> BigDecimal {
-    let big_decimal_zero: &BigDecimal = &BigDecimal::zero();
-    return if amount1.eq(big_decimal_zero) {
-        BigDecimal::zero()
+/// Port of `FullMath.mulDiv`: `floor(a * b / denominator)` with a 512-bit intermediate product
+pub fn mul_div(a: U256, b: U256, denominator: U256) -> U256 {
+    if denominator.is_zero() {
+        panic!("mul_div by zero");
+    }
+
+    let result = a.full_mul(b) / U512::from(denominator);
+    U256::try_from(result).expect("mul_div result overflows 256 bits")
+}
+
+/// Port of `FullMath.mulDivRoundingUp`: `ceil(a * b / denominator)`
+pub fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> U256 {
+    let result = mul_div(a, b, denominator);
+    if a.full_mul(b) % U512::from(denominator) != U512::zero() {
+        return result
+            .checked_add(U256::one())
+            .expect("mul_div result overflows 256 bits");
+    }
+
+    result
+}
+
+/// Port of `LiquidityAmounts.getAmount0ForLiquidity`: the amount of token0 held by `liquidity` between two
+/// sqrt prices, in raw token units
+pub fn get_amount0_for_liquidity(sqrt_ratio_a_x96: U256, sqrt_ratio_b_x96: U256, liquidity: U256) -> U256 {
+    let (sqrt_ratio_lower, sqrt_ratio_upper) = sorted(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    mul_div(liquidity << 96, sqrt_ratio_upper - sqrt_ratio_lower, sqrt_ratio_upper) / sqrt_ratio_lower
+}
+
+/// Port of `LiquidityAmounts.getAmount1ForLiquidity`: the amount of token1 held by `liquidity` between two
+/// sqrt prices, in raw token units
+pub fn get_amount1_for_liquidity(sqrt_ratio_a_x96: U256, sqrt_ratio_b_x96: U256, liquidity: U256) -> U256 {
+    let (sqrt_ratio_lower, sqrt_ratio_upper) = sorted(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+    mul_div(liquidity, sqrt_ratio_upper - sqrt_ratio_lower, U256::one() << 96)
+}
+
+/// Port of `LiquidityAmounts.getAmountsForLiquidity`: the token0 and token1 amounts of a position of
+/// `liquidity` over the range between two sqrt prices, at the current pool sqrt price
+pub fn get_amounts_for_liquidity(
+    sqrt_ratio_x96: U256,
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: U256,
+) -> (U256, U256) {
+    let (sqrt_ratio_lower, sqrt_ratio_upper) = sorted(sqrt_ratio_a_x96, sqrt_ratio_b_x96);
+
+    if sqrt_ratio_x96 <= sqrt_ratio_lower {
+        (
+            get_amount0_for_liquidity(sqrt_ratio_lower, sqrt_ratio_upper, liquidity),
+            U256::zero(),
+        )
+    } else if sqrt_ratio_x96 < sqrt_ratio_upper {
+        (
+            get_amount0_for_liquidity(sqrt_ratio_x96, sqrt_ratio_upper, liquidity),
+            get_amount1_for_liquidity(sqrt_ratio_lower, sqrt_ratio_x96, liquidity),
+        )
     } else {
-        amount0.clone().div(amount1.clone())
+        (
+            U256::zero(),
+            get_amount1_for_liquidity(sqrt_ratio_lower, sqrt_ratio_upper, liquidity),
+        )
+    }
+}
+
+/// Port of `Tick.getFeeGrowthInside` for one of the tokens: the fees earned per unit of liquidity within a
+/// tick range, as a Q128.128 which, like in the contract, is only meaningful as a difference with an older value
+pub fn get_fee_growth_inside(
+    tick_lower: i32,
+    tick_upper: i32,
+    tick_current: i32,
+    fee_growth_global_x128: U256,
+    fee_growth_outside_lower_x128: U256,
+    fee_growth_outside_upper_x128: U256,
+) -> U256 {
+    let fee_growth_below = if tick_current >= tick_lower {
+        fee_growth_outside_lower_x128
+    } else {
+        fee_growth_global_x128.overflowing_sub(fee_growth_outside_lower_x128).0
     };
+    let fee_growth_above = if tick_current < tick_upper {
+        fee_growth_outside_upper_x128
+    } else {
+        fee_growth_global_x128.overflowing_sub(fee_growth_outside_upper_x128).0
+    };
+
+    fee_growth_global_x128
+        .overflowing_sub(fee_growth_below)
+        .0
+        .overflowing_sub(fee_growth_above)
+        .0
+}
+
+/// Fees earned by `liquidity` since the fee growth inside of its range was `fee_growth_inside_last_x128`, in raw
+/// token units, as accrued by `Position.update`
+pub fn get_fees_earned(fee_growth_inside_x128: U256, fee_growth_inside_last_x128: U256, liquidity: U256) -> U256 {
+    mul_div(
+        fee_growth_inside_x128.overflowing_sub(fee_growth_inside_last_x128).0,
+        liquidity,
+        U256::one() << 128,
+    )
 }
 
-pub fn exponent_to_big_decimal(decimals: u64) -> BigDecimal {
-    let mut result = BigDecimal::one();
-    let big_decimal_ten: &BigDecimal = &BigDecimal::from(10 as i32);
+/// Parses an integer decoded from a 32 bytes storage slot as a signed value back to its `uint256` two's
+/// complement
+pub fn u256_from_signed(value: &str) -> U256 {
+    match value.strip_prefix("-") {
+        Some(abs_value) => U256::zero().overflowing_sub(U256::from_dec_str(abs_value).unwrap()).0,
+        None => U256::from_dec_str(value).unwrap(),
+    }
+}
 
-    let mut i = 1 as u64;
-    while i < decimals {
-        result = result.mul(big_decimal_ten.clone());
-        i += 1;
+fn sorted(a: U256, b: U256) -> (U256, U256) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
     }
+}
+
+/// `sqrt_price_x96^2 / 2^192`, the price of token0 in token1 raw amounts, rounded to `PRICE_PRECISION`
+/// significant digits. The division by a power of two always ends as a finite decimal, so it is computed
+/// exactly before rounding: `n / 2^192 = n * 5^192 / 10^192`
+pub fn sqrt_price_x96_to_price(sqrt_price_x96: U256) -> BigDecimal {
+    let mut bytes = [0u8; 64];
+    sqrt_price_x96.full_mul(sqrt_price_x96).to_big_endian(&mut bytes);
+    let digits = BigUint::from_bytes_be(&bytes) * BigUint::from(5u32).pow(192);
 
-    return result;
+    BigDecimal::from_str(&format!("{}e-192", digits))
+        .unwrap()
+        .with_prec(PRICE_PRECISION)
+}
+
+/// `1 / price`, rounded to `PRICE_PRECISION` significant digits, zero for a zero price
+pub fn inverse_price(price: &BigDecimal) -> BigDecimal {
+    safe_div(&BigDecimal::one(), price).with_prec(PRICE_PRECISION)
+}
+
+pub fn compute_price_from_tick_idx(desired_tick_idx: i32) -> BigDecimal {
+    sqrt_price_x96_to_price(get_sqrt_ratio_at_tick(desired_tick_idx))
+}
+
+pub fn safe_div(amount0: &BigDecimal, amount1: &BigDecimal) -> BigDecimal {
+    let big_decimal_zero: &BigDecimal = &BigDecimal::zero();
+    return if amount1.eq(big_decimal_zero) {
+        BigDecimal::zero()
+    } else {
+        amount0.clone().div(amount1.clone())
+    };
 }
 
 #[cfg(test)]
 mod test {
-    use crate::math::compute_price_from_tick_idx;
+    use crate::math::{
+        compute_price_from_tick_idx, get_amounts_for_liquidity, get_fee_growth_inside, get_fees_earned,
+        get_sqrt_ratio_at_tick, get_tick_at_sqrt_ratio, mul_div, mul_div_rounding_up, u256_from_signed, MAX_SQRT_RATIO,
+        MAX_TICK, MIN_SQRT_RATIO, MIN_TICK,
+    };
+    use ethabi::ethereum_types::U256;
     use std::str::FromStr;
     use substreams::prelude::BigDecimal;
 
+    fn u256(value: &str) -> U256 {
+        U256::from_dec_str(value).unwrap()
+    }
+
     #[test]
     fn test_positive_tick_idx() {
         let tick_idx = 257820;
         let actual_value = compute_price_from_tick_idx(tick_idx);
-        let expected_value = BigDecimal::from_str(
-            "157188409912.8279800665572784382799429044388135818770675416117949775512036146406973508030483126972441",
-        )
-        .unwrap();
+        let expected_value = BigDecimal::from_str("157188409912.8279800665572784382803").unwrap();
         assert_eq!(expected_value, actual_value);
     }
 
@@ -68,10 +295,146 @@ mod test {
     fn test_negative_tick_idx() {
         let tick_idx = -16200;
         let actual_value = compute_price_from_tick_idx(tick_idx);
-        let expected_value = BigDecimal::from_str(
-            "0.1979147284588052764428880652056914101428568621377186361720748341060154174725108347202390730358454528",
-        )
-        .unwrap();
+        let expected_value = BigDecimal::from_str("0.1979147284588052764428880652141991").unwrap();
         assert_eq!(expected_value, actual_value);
     }
+
+    #[test]
+    fn test_zero_tick_idx() {
+        assert_eq!(BigDecimal::one(), compute_price_from_tick_idx(0));
+    }
+
+    #[test]
+    fn test_sqrt_ratio_at_tick() {
+        assert_eq!(MIN_SQRT_RATIO, get_sqrt_ratio_at_tick(MIN_TICK));
+        assert_eq!(u256("4295343490"), get_sqrt_ratio_at_tick(MIN_TICK + 1));
+        assert_eq!(
+            u256("1461373636630004318706518188784493106690254656249"),
+            get_sqrt_ratio_at_tick(MAX_TICK - 1)
+        );
+        assert_eq!(MAX_SQRT_RATIO, get_sqrt_ratio_at_tick(MAX_TICK));
+        assert_eq!(U256::one() << 96, get_sqrt_ratio_at_tick(0));
+        assert_eq!(u256("79232123823359799118286999568"), get_sqrt_ratio_at_tick(1));
+        assert_eq!(u256("79224201403219477170569942574"), get_sqrt_ratio_at_tick(-1));
+        assert_eq!(u256("79625275426524748796330556128"), get_sqrt_ratio_at_tick(100));
+        assert_eq!(u256("78833030112140176575862854579"), get_sqrt_ratio_at_tick(-100));
+        assert_eq!(
+            u256("31411584497546210966172950056294283"),
+            get_sqrt_ratio_at_tick(257820)
+        );
+        assert_eq!(u256("35246714534369728900268601106"), get_sqrt_ratio_at_tick(-16200));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_ratio_at_tick_out_of_range() {
+        get_sqrt_ratio_at_tick(MAX_TICK + 1);
+    }
+
+    #[test]
+    fn test_tick_at_sqrt_ratio() {
+        assert_eq!(MIN_TICK, get_tick_at_sqrt_ratio(MIN_SQRT_RATIO));
+        assert_eq!(MIN_TICK + 1, get_tick_at_sqrt_ratio(u256("4295343490")));
+        assert_eq!(MAX_TICK - 1, get_tick_at_sqrt_ratio(MAX_SQRT_RATIO - 1));
+        assert_eq!(0, get_tick_at_sqrt_ratio(U256::one() << 96));
+    }
+
+    #[test]
+    fn test_tick_round_trip() {
+        for tick in (MIN_TICK..MAX_TICK).step_by(997) {
+            let sqrt_ratio = get_sqrt_ratio_at_tick(tick);
+            assert_eq!(tick, get_tick_at_sqrt_ratio(sqrt_ratio));
+            assert_eq!(tick, get_tick_at_sqrt_ratio(get_sqrt_ratio_at_tick(tick + 1) - 1));
+        }
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let q128 = U256::one() << 128;
+        assert_eq!(q128 / 3, mul_div(q128, q128 * 50 / 100, q128 * 150 / 100));
+        assert_eq!(q128 * 4375 / 1000, mul_div(q128, q128 * 35, q128 * 8));
+        assert_eq!(
+            U256::max_value(),
+            mul_div(U256::max_value(), U256::max_value(), U256::max_value())
+        );
+
+        assert_eq!(
+            q128 / 3 + 1,
+            mul_div_rounding_up(q128, q128 * 50 / 100, q128 * 150 / 100)
+        );
+        assert_eq!(q128 * 4375 / 1000, mul_div_rounding_up(q128, q128 * 35, q128 * 8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_div_overflow() {
+        mul_div(U256::max_value(), U256::max_value(), U256::one());
+    }
+
+    #[test]
+    fn test_amounts_for_liquidity() {
+        // sqrt prices of 100/110, 1/1 and 110/100
+        let sqrt_ratio_lower = u256("75541088972021052632782079082");
+        let sqrt_ratio_upper = u256("83095197869223157896060286990");
+
+        let in_range = u256("79228162514264337593543950336");
+        let amounts = get_amounts_for_liquidity(in_range, sqrt_ratio_lower, sqrt_ratio_upper, U256::from(2148));
+        assert_eq!((U256::from(99), U256::from(99)), amounts);
+
+        // below the range the position is all token0, above it all token1
+        let below = u256("75162434512514379355924140470");
+        let amounts = get_amounts_for_liquidity(below, sqrt_ratio_lower, sqrt_ratio_upper, U256::from(1048));
+        assert_eq!((U256::from(99), U256::zero()), amounts);
+        let amounts = get_amounts_for_liquidity(sqrt_ratio_lower, sqrt_ratio_lower, sqrt_ratio_upper, U256::from(1048));
+        assert_eq!((U256::from(99), U256::zero()), amounts);
+
+        let above = u256("83472048772503575395058907992");
+        let amounts = get_amounts_for_liquidity(above, sqrt_ratio_lower, sqrt_ratio_upper, U256::from(2097));
+        assert_eq!((U256::zero(), U256::from(199)), amounts);
+        let amounts = get_amounts_for_liquidity(sqrt_ratio_upper, sqrt_ratio_upper, sqrt_ratio_lower, U256::from(2097));
+        assert_eq!((U256::zero(), U256::from(199)), amounts);
+    }
+
+    #[test]
+    fn test_fee_growth_inside() {
+        let q128 = U256::one() << 128;
+        let global = q128 * 10;
+
+        // in range, the ticks hold the growth below the lower one and above the upper one
+        assert_eq!(q128 * 5, get_fee_growth_inside(-60, 60, 0, global, q128 * 2, q128 * 3));
+        // below the range, they both hold the growth above them
+        assert_eq!(
+            q128 * 2,
+            get_fee_growth_inside(-60, 60, -120, global, q128 * 5, q128 * 3)
+        );
+        // above the range, they both hold the growth below them
+        assert_eq!(
+            q128 * 5,
+            get_fee_growth_inside(-60, 60, 120, global, q128 * 2, q128 * 7)
+        );
+        // the fee growths wrap around like the contract's unchecked arithmetic
+        assert_eq!(
+            U256::max_value() - q128 * 5 + 1,
+            get_fee_growth_inside(-60, 60, 0, U256::zero(), q128 * 2, q128 * 3)
+        );
+    }
+
+    #[test]
+    fn test_fees_earned() {
+        let q128 = U256::one() << 128;
+        assert_eq!(U256::from(1500), get_fees_earned(q128 * 3, q128, U256::from(750)));
+        assert_eq!(U256::zero(), get_fees_earned(q128, q128, U256::from(750)));
+        // the fee growth inside wrapped around since the last update
+        assert_eq!(
+            U256::from(1500),
+            get_fees_earned(q128, U256::max_value() - q128 + 1, U256::from(750))
+        );
+    }
+
+    #[test]
+    fn test_u256_from_signed() {
+        assert_eq!(U256::from(42), u256_from_signed("42"));
+        assert_eq!(U256::max_value(), u256_from_signed("-1"));
+        assert_eq!(U256::max_value() - 41, u256_from_signed("-42"));
+    }
 }
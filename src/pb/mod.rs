@@ -4,10 +4,10 @@ use crate::pb::uniswap::events::position_event::Type::{
 };
 use crate::pb::uniswap::events::PoolEvent;
 use crate::pb::uniswap::events::PositionEvent;
-use crate::utils::ERROR_POOL;
+use crate::pb::uniswap::events::RawPositionUpdate;
 use crate::{Erc20Token, Pool};
 use substreams::scalar::BigDecimal;
-use substreams::{log, Hex};
+use substreams::log;
 
 #[allow(unused_imports)]
 #[allow(dead_code)]
@@ -31,6 +31,16 @@ impl PositionEvent {
     }
 }
 
+impl RawPositionUpdate {
+    // the pool positions map key: pool, owner and tick range
+    pub fn id(&self) -> String {
+        format!(
+            "0x{}#0x{}#{}#{}",
+            self.pool_address, self.owner, self.tick_lower, self.tick_upper
+        )
+    }
+}
+
 impl Erc20Token {
     pub fn log(&self) {
         log::info!(
@@ -45,17 +55,13 @@ impl Erc20Token {
 
 impl Pool {
     pub fn should_handle_swap(&self) -> bool {
-        if self.ignore_pool {
-            return false;
-        }
-        return &self.address != &Hex(ERROR_POOL).to_string();
+        // the `ignored_pools` of the network, like the mainnet error pool, are flagged when created
+        !self.ignore_pool
     }
 
     pub fn should_handle_mint_and_burn(&self) -> bool {
-        if self.ignore_pool {
-            return false;
-        }
-        return &self.address != &Hex(ERROR_POOL).to_string();
+        // the `ignored_pools` of the network, like the mainnet error pool, are flagged when created
+        !self.ignore_pool
     }
 
     pub fn token0_ref(&self) -> &Erc20Token {
@@ -121,6 +127,8 @@ impl PoolEvent {
                 token0_addr: self.token0.clone(),
                 token1_addr: self.token1.clone(),
             }),
+            // The principal withdrawn by a collect was already removed from the pool on the burn
+            Type::Collect(_) => None,
         };
     }
 }
@@ -64,6 +64,50 @@ pub struct Pool {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FactoryEvents {
+    #[prost(message, repeated, tag="1")]
+    pub fee_amounts_enabled: ::prost::alloc::vec::Vec<factory_events::FeeAmountEnabled>,
+    #[prost(message, repeated, tag="2")]
+    pub owner_changes: ::prost::alloc::vec::Vec<factory_events::OwnerChanged>,
+}
+/// Nested message and enum types in `FactoryEvents`.
+pub mod factory_events {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeAmountEnabled {
+        /// Integer
+        #[prost(string, tag="1")]
+        pub fee: ::prost::alloc::string::String,
+        #[prost(int32, tag="2")]
+        pub tick_spacing: i32,
+        #[prost(string, tag="3")]
+        pub transaction_id: ::prost::alloc::string::String,
+        #[prost(uint64, tag="4")]
+        pub timestamp: u64,
+        #[prost(uint64, tag="5")]
+        pub block_number: u64,
+        #[prost(uint64, tag="6")]
+        pub log_ordinal: u64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct OwnerChanged {
+        #[prost(string, tag="1")]
+        pub old_owner: ::prost::alloc::string::String,
+        #[prost(string, tag="2")]
+        pub new_owner: ::prost::alloc::string::String,
+        #[prost(string, tag="3")]
+        pub transaction_id: ::prost::alloc::string::String,
+        #[prost(uint64, tag="4")]
+        pub timestamp: u64,
+        #[prost(uint64, tag="5")]
+        pub block_number: u64,
+        #[prost(uint64, tag="6")]
+        pub log_ordinal: u64,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Events {
     #[prost(message, repeated, tag="1")]
     pub pool_sqrt_prices: ::prost::alloc::vec::Vec<events::PoolSqrtPrice>,
@@ -85,6 +129,18 @@ pub struct Events {
     pub ticks_created: ::prost::alloc::vec::Vec<events::TickCreated>,
     #[prost(message, repeated, tag="9")]
     pub ticks_updated: ::prost::alloc::vec::Vec<events::TickUpdated>,
+    #[prost(message, repeated, tag="12")]
+    pub fee_protocol_updates: ::prost::alloc::vec::Vec<events::FeeProtocolUpdate>,
+    #[prost(message, repeated, tag="13")]
+    pub protocol_fees_accrued: ::prost::alloc::vec::Vec<events::ProtocolFeesAccrued>,
+    #[prost(message, repeated, tag="14")]
+    pub collect_protocols: ::prost::alloc::vec::Vec<events::CollectProtocol>,
+    #[prost(message, repeated, tag="15")]
+    pub pool_oracle_updates: ::prost::alloc::vec::Vec<events::PoolOracle>,
+    #[prost(message, repeated, tag="16")]
+    pub observations: ::prost::alloc::vec::Vec<events::Observation>,
+    #[prost(message, repeated, tag="17")]
+    pub ticks_crossed: ::prost::alloc::vec::Vec<events::TickCrossed>,
     #[prost(message, repeated, tag="20")]
     pub created_positions: ::prost::alloc::vec::Vec<events::CreatedPosition>,
     #[prost(message, repeated, tag="21")]
@@ -95,6 +151,10 @@ pub struct Events {
     pub collect_positions: ::prost::alloc::vec::Vec<events::CollectPosition>,
     #[prost(message, repeated, tag="24")]
     pub transfer_positions: ::prost::alloc::vec::Vec<events::TransferPosition>,
+    #[prost(message, repeated, tag="25")]
+    pub raw_position_updates: ::prost::alloc::vec::Vec<events::RawPositionUpdate>,
+    #[prost(message, repeated, tag="26")]
+    pub tick_bitmap_updates: ::prost::alloc::vec::Vec<events::TickBitmapUpdate>,
 }
 /// Nested message and enum types in `Events`.
 pub mod events {
@@ -181,6 +241,52 @@ pub mod events {
         pub fee_growth_outside_1x_128: ::prost::alloc::string::String,
         #[prost(uint64, tag="6")]
         pub timestamp: u64,
+        /// Integer, the tick values are empty when they did not change
+        #[prost(string, tag="7")]
+        pub liquidity_gross: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="8")]
+        pub liquidity_net: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="9")]
+        pub tick_cumulative_outside: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="10")]
+        pub seconds_per_liquidity_outside_x128: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="11")]
+        pub seconds_outside: ::prost::alloc::string::String,
+    }
+    /// a tick crossed by a swap, its fee growths outside were flipped
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TickCrossed {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="2")]
+        pub idx: ::prost::alloc::string::String,
+        #[prost(uint64, tag="3")]
+        pub log_ordinal: u64,
+        /// Integer
+        #[prost(string, tag="4")]
+        pub fee_growth_outside_0x_128: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="5")]
+        pub fee_growth_outside_1x_128: ::prost::alloc::string::String,
+        #[prost(uint64, tag="6")]
+        pub timestamp: u64,
+        #[prost(string, tag="7")]
+        pub transaction_id: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="8")]
+        pub tick_cumulative_outside: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="9")]
+        pub seconds_per_liquidity_outside_x128: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="10")]
+        pub seconds_outside: ::prost::alloc::string::String,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -219,7 +325,7 @@ pub mod events {
         pub timestamp: u64,
         #[prost(uint64, tag="108")]
         pub created_at_block_number: u64,
-        #[prost(oneof="pool_event::Type", tags="1, 2, 3")]
+        #[prost(oneof="pool_event::Type", tags="1, 2, 3, 4")]
         pub r#type: ::core::option::Option<pool_event::Type>,
     }
     /// Nested message and enum types in `PoolEvent`.
@@ -248,6 +354,12 @@ pub mod events {
             /// Integer
             #[prost(string, tag="8")]
             pub tick: ::prost::alloc::string::String,
+            /// Integer, the pool tick before the swap
+            #[prost(string, tag="9")]
+            pub tick_before: ::prost::alloc::string::String,
+            /// Integer, the pool sqrt price before the swap
+            #[prost(string, tag="10")]
+            pub sqrt_price_before: ::prost::alloc::string::String,
         }
         #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -298,6 +410,26 @@ pub mod events {
             pub amount: ::prost::alloc::string::String,
         }
         #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Collect {
+            #[prost(string, tag="1")]
+            pub owner: ::prost::alloc::string::String,
+            #[prost(string, tag="2")]
+            pub recipient: ::prost::alloc::string::String,
+            /// Decimal
+            #[prost(string, tag="3")]
+            pub amount_0: ::prost::alloc::string::String,
+            /// Decimal
+            #[prost(string, tag="4")]
+            pub amount_1: ::prost::alloc::string::String,
+            /// Integer
+            #[prost(string, tag="5")]
+            pub tick_lower: ::prost::alloc::string::String,
+            /// Integer
+            #[prost(string, tag="6")]
+            pub tick_upper: ::prost::alloc::string::String,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum Type {
             #[prost(message, tag="1")]
@@ -306,8 +438,45 @@ pub mod events {
             Burn(Burn),
             #[prost(message, tag="3")]
             Mint(Mint),
+            #[prost(message, tag="4")]
+            Collect(Collect),
         }
     }
+    /// only the oracle fields that changed are set
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PoolOracle {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag="2")]
+        pub log_ordinal: u64,
+        #[prost(uint32, optional, tag="3")]
+        pub observation_index: ::core::option::Option<u32>,
+        #[prost(uint32, optional, tag="4")]
+        pub observation_cardinality: ::core::option::Option<u32>,
+        #[prost(uint32, optional, tag="5")]
+        pub observation_cardinality_next: ::core::option::Option<u32>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Observation {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag="2")]
+        pub log_ordinal: u64,
+        #[prost(uint32, tag="3")]
+        pub index: u32,
+        #[prost(uint32, tag="4")]
+        pub block_timestamp: u32,
+        /// Integer
+        #[prost(string, tag="5")]
+        pub tick_cumulative: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="6")]
+        pub seconds_per_liquidity_cumulative_x128: ::prost::alloc::string::String,
+        #[prost(bool, tag="7")]
+        pub initialized: bool,
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PoolLiquidity {
@@ -329,14 +498,97 @@ pub mod events {
     pub struct Flash {
         #[prost(string, tag="1")]
         pub pool_address: ::prost::alloc::string::String,
-        /// Integer
-        #[prost(string, tag="2")]
-        pub fee_growth_global_0x_128: ::prost::alloc::string::String,
-        /// Integer
-        #[prost(string, tag="3")]
-        pub fee_growth_global_1x_128: ::prost::alloc::string::String,
         #[prost(uint64, tag="4")]
         pub log_ordinal: u64,
+        #[prost(uint64, tag="5")]
+        pub log_index: u64,
+        #[prost(string, tag="6")]
+        pub token0: ::prost::alloc::string::String,
+        #[prost(string, tag="7")]
+        pub token1: ::prost::alloc::string::String,
+        #[prost(string, tag="8")]
+        pub transaction_id: ::prost::alloc::string::String,
+        #[prost(uint64, tag="9")]
+        pub timestamp: u64,
+        #[prost(string, tag="10")]
+        pub sender: ::prost::alloc::string::String,
+        #[prost(string, tag="11")]
+        pub recipient: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="12")]
+        pub amount_0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="13")]
+        pub amount_1: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="14")]
+        pub amount_0_paid: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="15")]
+        pub amount_1_paid: ::prost::alloc::string::String,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeProtocolUpdate {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag="2")]
+        pub log_ordinal: u64,
+        /// denominator of the protocol's share of the swap fees, 0 when the switch is off
+        #[prost(uint32, tag="3")]
+        pub fee_protocol_0: u32,
+        #[prost(uint32, tag="4")]
+        pub fee_protocol_1: u32,
+        #[prost(uint32, tag="5")]
+        pub fee_protocol_0_old: u32,
+        #[prost(uint32, tag="6")]
+        pub fee_protocol_1_old: u32,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ProtocolFeesAccrued {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag="2")]
+        pub log_ordinal: u64,
+        #[prost(string, tag="3")]
+        pub token0: ::prost::alloc::string::String,
+        #[prost(string, tag="4")]
+        pub token1: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="5")]
+        pub amount_0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="6")]
+        pub amount_1: ::prost::alloc::string::String,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CollectProtocol {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(uint64, tag="2")]
+        pub log_ordinal: u64,
+        #[prost(uint64, tag="3")]
+        pub log_index: u64,
+        #[prost(string, tag="4")]
+        pub token0: ::prost::alloc::string::String,
+        #[prost(string, tag="5")]
+        pub token1: ::prost::alloc::string::String,
+        #[prost(string, tag="6")]
+        pub transaction_id: ::prost::alloc::string::String,
+        #[prost(uint64, tag="7")]
+        pub timestamp: u64,
+        #[prost(string, tag="8")]
+        pub sender: ::prost::alloc::string::String,
+        #[prost(string, tag="9")]
+        pub recipient: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="10")]
+        pub amount_0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="11")]
+        pub amount_1: ::prost::alloc::string::String,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -428,6 +680,15 @@ pub mod events {
         /// BigInt
         #[prost(string, optional, tag="6")]
         pub fee_growth_inside1_last_x128: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt, liquidity of the position after the event
+        #[prost(string, optional, tag="7")]
+        pub position_liquidity: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt, fees and withdrawn amounts the position can collect
+        #[prost(string, optional, tag="8")]
+        pub tokens_owed0: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt
+        #[prost(string, optional, tag="9")]
+        pub tokens_owed1: ::core::option::Option<::prost::alloc::string::String>,
         #[prost(uint64, tag="10")]
         pub log_ordinal: u64,
     }
@@ -451,6 +712,15 @@ pub mod events {
         /// BigInt
         #[prost(string, optional, tag="6")]
         pub fee_growth_inside1_last_x128: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt, liquidity of the position after the event
+        #[prost(string, optional, tag="7")]
+        pub position_liquidity: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt, fees and withdrawn amounts the position can collect
+        #[prost(string, optional, tag="8")]
+        pub tokens_owed0: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt
+        #[prost(string, optional, tag="9")]
+        pub tokens_owed1: ::core::option::Option<::prost::alloc::string::String>,
         #[prost(uint64, tag="10")]
         pub log_ordinal: u64,
     }
@@ -471,6 +741,12 @@ pub mod events {
         /// BigInt
         #[prost(string, optional, tag="6")]
         pub fee_growth_inside1_last_x128: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt, fees and withdrawn amounts the position can collect
+        #[prost(string, optional, tag="7")]
+        pub tokens_owed0: ::core::option::Option<::prost::alloc::string::String>,
+        /// BigInt
+        #[prost(string, optional, tag="8")]
+        pub tokens_owed1: ::core::option::Option<::prost::alloc::string::String>,
         #[prost(uint64, tag="10")]
         pub log_ordinal: u64,
     }
@@ -484,6 +760,204 @@ pub mod events {
         #[prost(uint64, tag="10")]
         pub log_ordinal: u64,
     }
+    /// a pool position, keyed by owner and tick range, changed by a pool Mint, Burn or Collect
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct RawPositionUpdate {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(string, tag="2")]
+        pub owner: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="3")]
+        pub tick_lower: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="4")]
+        pub tick_upper: ::prost::alloc::string::String,
+        #[prost(string, tag="5")]
+        pub token0: ::prost::alloc::string::String,
+        #[prost(string, tag="6")]
+        pub token1: ::prost::alloc::string::String,
+        #[prost(string, tag="7")]
+        pub transaction_id: ::prost::alloc::string::String,
+        #[prost(uint64, tag="8")]
+        pub log_ordinal: u64,
+        #[prost(uint64, tag="9")]
+        pub timestamp: u64,
+        #[prost(uint64, tag="10")]
+        pub block_number: u64,
+        /// Integer, liquidity added by a Mint, negative when removed by a Burn
+        #[prost(string, tag="11")]
+        pub liquidity_delta: ::prost::alloc::string::String,
+        /// Decimal, amounts added by a Mint
+        #[prost(string, tag="12")]
+        pub deposited_token0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="13")]
+        pub deposited_token1: ::prost::alloc::string::String,
+        /// Decimal, amounts removed by a Burn
+        #[prost(string, tag="14")]
+        pub withdrawn_token0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="15")]
+        pub withdrawn_token1: ::prost::alloc::string::String,
+        /// Decimal, amounts sent out by a Collect
+        #[prost(string, tag="16")]
+        pub collected_token0: ::prost::alloc::string::String,
+        /// Decimal
+        #[prost(string, tag="17")]
+        pub collected_token1: ::prost::alloc::string::String,
+        /// Integer, the position values decoded from the pool positions mapping, set when they changed
+        #[prost(string, optional, tag="18")]
+        pub fee_growth_inside0_last_x128: ::core::option::Option<::prost::alloc::string::String>,
+        /// Integer
+        #[prost(string, optional, tag="19")]
+        pub fee_growth_inside1_last_x128: ::core::option::Option<::prost::alloc::string::String>,
+        /// Integer
+        #[prost(string, optional, tag="20")]
+        pub tokens_owed0: ::core::option::Option<::prost::alloc::string::String>,
+        /// Integer
+        #[prost(string, optional, tag="21")]
+        pub tokens_owed1: ::core::option::Option<::prost::alloc::string::String>,
+    }
+    /// a word of the pool tickBitmap flipped by a Mint or a Burn
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TickBitmapUpdate {
+        #[prost(string, tag="1")]
+        pub pool_address: ::prost::alloc::string::String,
+        #[prost(int32, tag="2")]
+        pub word_pos: i32,
+        /// Integer, uint256 bitmap of the word
+        #[prost(string, tag="3")]
+        pub old_value: ::prost::alloc::string::String,
+        /// Integer
+        #[prost(string, tag="4")]
+        pub new_value: ::prost::alloc::string::String,
+        #[prost(uint64, tag="5")]
+        pub log_ordinal: u64,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolTwaps {
+    #[prost(message, repeated, tag="1")]
+    pub twaps: ::prost::alloc::vec::Vec<PoolTwap>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolTwap {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// length of the averaging window in seconds
+    #[prost(uint32, tag="4")]
+    pub window: u32,
+    #[prost(int32, tag="5")]
+    pub arithmetic_mean_tick: i32,
+    /// Decimal
+    #[prost(string, tag="6")]
+    pub price0: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="7")]
+    pub price1: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceOutliers {
+    #[prost(message, repeated, tag="1")]
+    pub outliers: ::prost::alloc::vec::Vec<PriceOutlier>,
+}
+/// Pool price update moving too far from the previous block price
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceOutlier {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    /// ordinal of the swap log
+    #[prost(uint64, tag="2")]
+    pub ordinal: u64,
+    /// Decimal, token0 price at the end of the previous block
+    #[prost(string, tag="3")]
+    pub previous_price0: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="4")]
+    pub price0: ::prost::alloc::string::String,
+    /// Integer, in range liquidity of the pool at the end of the previous block
+    #[prost(string, tag="5")]
+    pub previous_liquidity: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DerivedPrices {
+    #[prost(message, repeated, tag="1")]
+    pub prices: ::prost::alloc::vec::Vec<DerivedPrice>,
+}
+/// Prices derived on a pool price update
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DerivedPrice {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub ordinal: u64,
+    /// the update is the pool initialization
+    #[prost(bool, tag="3")]
+    pub initialized: bool,
+    /// Decimal, median of the included bundle sources
+    #[prost(string, tag="4")]
+    pub bundle_eth_price_usd: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="5")]
+    pub bundle_sources: ::prost::alloc::vec::Vec<BundlePriceSource>,
+    #[prost(message, optional, tag="6")]
+    pub token0: ::core::option::Option<TokenPrice>,
+    #[prost(message, optional, tag="7")]
+    pub token1: ::core::option::Option<TokenPrice>,
+    #[prost(message, repeated, tag="8")]
+    pub quote_prices: ::prost::alloc::vec::Vec<QuotePrice>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuotePrice {
+    #[prost(string, tag="1")]
+    pub quote_token: ::prost::alloc::string::String,
+    /// Decimal, ETH price of the quote token
+    #[prost(string, tag="2")]
+    pub derived_eth: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BundlePriceSource {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    /// Decimal, zero when the pool has no in-range liquidity
+    #[prost(string, tag="2")]
+    pub eth_price_usd: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenPrice {
+    #[prost(string, tag="1")]
+    pub token_address: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="2")]
+    pub derived_eth: ::prost::alloc::string::String,
+    /// pool the price was derived from, empty for the wrapped native token and the stable coins
+    #[prost(string, tag="3")]
+    pub source_pool: ::prost::alloc::string::String,
+    /// Decimal, ETH value of the counter token locked in the source pool
+    #[prost(string, tag="4")]
+    pub eth_locked: ::prost::alloc::string::String,
+    /// number of pools between the token and the wrapped native token
+    #[prost(uint32, tag="5")]
+    pub hops: u32,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -539,3 +1013,65 @@ pub struct SnapshotPosition {
     pub log_ordinal: u64,
 }
 // @@protoc_insertion_point(module)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionAmounts {
+    #[prost(message, repeated, tag="1")]
+    pub amounts: ::prost::alloc::vec::Vec<PositionAmount>,
+}
+/// Token amounts held by a position at the current pool price
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionAmount {
+    /// the token_id of the position
+    #[prost(string, tag="1")]
+    pub position: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    /// Integer
+    #[prost(string, tag="3")]
+    pub liquidity: ::prost::alloc::string::String,
+    /// Integer
+    #[prost(string, tag="4")]
+    pub sqrt_price: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="5")]
+    pub amount0: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="6")]
+    pub amount1: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="7")]
+    pub amount_usd: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Decimal, fees earned since the last update of the position, not credited to its tokens owed yet
+    #[prost(string, tag="9")]
+    pub uncollected_fees_token0: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="10")]
+    pub uncollected_fees_token1: ::prost::alloc::string::String,
+    /// Decimal
+    #[prost(string, tag="11")]
+    pub uncollected_fees_usd: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializedTicks {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<PoolInitializedTicks>,
+}
+/// Every initialized tick of a pool, read from its tickBitmap
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolInitializedTicks {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    #[prost(int32, tag="2")]
+    pub tick_spacing: i32,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// ascending
+    #[prost(int32, repeated, tag="4")]
+    pub ticks: ::prost::alloc::vec::Vec<i32>,
+}
@@ -0,0 +1,71 @@
+// the blocks of the highest level hold 2^21 ticks, more than the whole tick range
+const MAX_BLOCK_LEVEL: u32 = 21;
+
+/// Aligned blocks of ticks covering a position range `[tick_lower, tick_upper)`, like the nodes of a segment tree:
+/// a block `(level, index)` holds the `2^level` ticks from `index * 2^level`. A range is covered by at most two
+/// blocks per level, and a position contains a tick when one of its blocks is a block of that tick.
+pub fn range_blocks(tick_lower: i32, tick_upper: i32) -> Vec<(u32, i32)> {
+    let mut blocks = vec![];
+    let mut start = tick_lower;
+    while start < tick_upper {
+        let mut level = 0;
+        while level < MAX_BLOCK_LEVEL
+            && start.rem_euclid(1 << (level + 1)) == 0
+            && start as i64 + (1i64 << (level + 1)) <= tick_upper as i64
+        {
+            level += 1;
+        }
+        blocks.push((level, start >> level));
+        start += 1 << level;
+    }
+    blocks
+}
+
+/// The blocks holding a tick, one per level
+pub fn tick_blocks(tick: i32) -> Vec<(u32, i32)> {
+    (0..=MAX_BLOCK_LEVEL).map(|level| (level, tick >> level)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{MAX_TICK, MIN_TICK};
+
+    fn contains(tick_lower: i32, tick_upper: i32, tick: i32) -> bool {
+        let blocks = range_blocks(tick_lower, tick_upper);
+        tick_blocks(tick).iter().any(|block| blocks.contains(block))
+    }
+
+    #[test]
+    fn blocks_of_a_range() {
+        assert_eq!(vec![(2, 0)], range_blocks(0, 4));
+        assert_eq!(vec![(0, 1), (1, 1), (2, 1), (0, 8)], range_blocks(1, 9));
+        assert_eq!(vec![(2, -1), (2, 0)], range_blocks(-4, 4));
+        assert_eq!(Vec::<(u32, i32)>::new(), range_blocks(60, 60));
+    }
+
+    #[test]
+    fn blocks_of_the_full_range() {
+        let blocks = range_blocks(MIN_TICK, MAX_TICK);
+        assert!(blocks.len() <= 2 * (MAX_BLOCK_LEVEL as usize + 1));
+        assert!(contains(MIN_TICK, MAX_TICK, MIN_TICK));
+        assert!(contains(MIN_TICK, MAX_TICK, 0));
+        assert!(contains(MIN_TICK, MAX_TICK, MAX_TICK - 1));
+        assert!(!contains(MIN_TICK, MAX_TICK, MAX_TICK));
+    }
+
+    #[test]
+    fn ticks_of_a_range() {
+        for (tick_lower, tick_upper) in [(-887220, 887220), (-600, 60), (-200, -60), (1000, 20000), (-7, 13)] {
+            for tick in [
+                -887221, -887220, -601, -600, -201, -200, -61, -60, -7, -1, 0, 12, 13, 59, 60, 999, 19999,
+            ] {
+                assert_eq!(
+                    tick_lower <= tick && tick < tick_upper,
+                    contains(tick_lower, tick_upper, tick),
+                    "tick {tick} in [{tick_lower}, {tick_upper})"
+                );
+            }
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use crate::config::PricingConfig;
+use crate::pb::uniswap::PriceOutliers;
 use crate::{math, Erc20Token, Pool};
+use ethabi::ethereum_types::U256;
+use std::collections::HashMap;
 use std::ops::{Div, Mul};
 use std::str;
 use std::str::FromStr;
@@ -6,45 +10,8 @@ use substreams::log;
 use substreams::scalar::{BigDecimal, BigInt};
 use substreams::store::{StoreGet, StoreGetBigDecimal, StoreGetBigInt, StoreGetProto, StoreGetRaw};
 
-const USDC_WETH_03_POOL: &str = "8ad599c3a0ff1de082011efddc58f1908eb6e6d8";
-const USDC_ADDRESS: &str = "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
-const WETH_ADDRESS: &str = "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
-
-pub const STABLE_COINS: [&str; 6] = [
-    "6b175474e89094c44da98b954eedeac495271d0f", // DAI
-    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
-    "dac17f958d2ee523a2206206994597c13d831ec7", // USDT
-    "0000000000085d4780b73119b644ae5ecd22b376", // TUSD
-    "956f47f50a910163d8bf957cf5846d573e7f87ca", // FEI
-    "4dd28568d05f09b02220b09c2cb307bfd837cb95", // PRINTS
-];
-
-pub const WHITELIST_TOKENS: [&str; 21] = [
-    "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", // WETH
-    "6b175474e89094c44da98b954eedeac495271d0f", // DAI
-    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", // USDC
-    "dac17f958d2ee523a2206206994597c13d831ec7", // USDT
-    "0000000000085d4780b73119b644ae5ecd22b376", // TUSD
-    "2260fac5e5542a773aa44fbcfedf7c193bc2c599", // WBTC
-    "5d3a536e4d6dbd6114cc1ead35777bab948e3643", // cDAI
-    "39aa39c021dfbae8fac545936693ac917d5e7563", // cUSDC
-    "86fadb80d8d2cff3c3680819e4da99c10232ba0f", // EBASE
-    "57ab1ec28d129707052df4df418d58a2d46d5f51", // sUSD
-    "9f8f72aa9304c8b593d555f12ef6589cc3a579a2", // MKR
-    "c00e94cb662c3520282e6f5717214004a7f26888", // COMP
-    "514910771af9ca656af840dff83e8264ecf986ca", // LINK
-    "c011a73ee8576fb46f5e1c5751ca3b9fe0af2a6f", // SNX
-    "0bc529c00c6401aef6d220be8c6ea1667f6ad93e", // YFI
-    "111111111117dc0aa78b770fa6a738034120c302", // 1INCH
-    "df5e0e81dff6faf3a7e52ba697820c5e32d806a8", // yCurv
-    "956f47f50a910163d8bf957cf5846d573e7f87ca", // FEI
-    "7d1afa7b718fb893db30a3abc0cfc608aacfebb0", // MATIC
-    "7fc66500c84a76ad7e9c93437bfc5ac33e2ddae9", // AAVE
-    "fe2e637202056d30016725477c5da089ab0a043a", // sETH2
-];
-
 pub fn sqrt_price_x96_to_token_prices(
-    sqrt_price: BigDecimal,
+    sqrt_price_x96: U256,
     token_0: &Erc20Token,
     token_1: &Erc20Token,
 ) -> (BigDecimal, BigDecimal) {
@@ -56,20 +23,85 @@ pub fn sqrt_price_x96_to_token_prices(
         token_1.decimals
     );
 
-    let price: BigDecimal = sqrt_price.clone().mul(sqrt_price);
-    let denominator: BigDecimal =
-        BigDecimal::from_str("6277101735386680763835789423207666416102355444464034512896").unwrap();
-
-    let price1 = price
-        .div(denominator)
-        .mul(math::exponent_to_big_decimal(token_0.decimals))
-        .div(math::exponent_to_big_decimal(token_1.decimals));
+    // scaling by 10^(decimals0 - decimals1) instead of dividing keeps the significant digits
+    let decimals_factor =
+        BigDecimal::from_str(&format!("1e{}", token_0.decimals as i64 - token_1.decimals as i64)).unwrap();
+    let price1 = math::sqrt_price_x96_to_price(sqrt_price_x96).mul(decimals_factor);
 
-    let price0 = math::safe_div(&BigDecimal::one(), &price1);
+    let price0 = math::inverse_price(&price1);
 
     return (price0, price1);
 }
 
+/// ETH price of a token and where it was derived from
+pub struct EthPerToken {
+    pub price: BigDecimal,
+    /// pool the price was derived from, empty for the wrapped native token and the stable coins
+    pub source_pool: String,
+    /// ETH value of the counter token locked in the source pool
+    pub eth_locked: BigDecimal,
+    /// number of pools between the token and the wrapped native token
+    pub hops: u32,
+}
+
+impl EthPerToken {
+    fn unsourced(price: BigDecimal) -> EthPerToken {
+        EthPerToken {
+            price,
+            source_pool: String::new(),
+            eth_locked: BigDecimal::zero(),
+            hops: 0,
+        }
+    }
+}
+
+/// Pool prices read by the price derivation. The keys written by a price update flagged by
+/// `map_price_outliers` are read at their previous block value from that update to the end of the block.
+pub struct PoolPrices<'a> {
+    store: &'a StoreGetBigDecimal,
+    outlier_keys: HashMap<String, u64>,
+}
+
+impl<'a> PoolPrices<'a> {
+    pub fn new(
+        store: &'a StoreGetBigDecimal,
+        pools_store: &StoreGetProto<Pool>,
+        price_outliers: &PriceOutliers,
+    ) -> PoolPrices<'a> {
+        let mut outlier_keys = HashMap::new();
+        for outlier in &price_outliers.outliers {
+            let pool_address = &outlier.pool_address;
+            let pool = match pools_store.get_last(format!("pool:{pool_address}")) {
+                None => continue,
+                Some(pool) => pool,
+            };
+            let token0_addr = &pool.token0.as_ref().unwrap().address;
+            let token1_addr = &pool.token1.as_ref().unwrap().address;
+
+            // the keys set by `store_prices`, the outliers are sorted by ordinal
+            for key in [
+                format!("pool:{pool_address}:{token0_addr}:token0"),
+                format!("pool:{pool_address}:{token1_addr}:token1"),
+                format!("pair:{token0_addr}:{token1_addr}"),
+                format!("pair:{token1_addr}:{token0_addr}"),
+            ] {
+                outlier_keys.entry(key).or_insert(outlier.ordinal);
+            }
+        }
+
+        PoolPrices { store, outlier_keys }
+    }
+
+    pub fn get_at<K: AsRef<str>>(&self, ord: u64, key: K) -> Option<BigDecimal> {
+        let key = key.as_ref();
+        match self.outlier_keys.get(key) {
+            // values at ordinal 0 are the ones left by the previous block
+            Some(outlier_ord) if *outlier_ord <= ord => self.store.get_at(0, key),
+            _ => self.store.get_at(ord, key),
+        }
+    }
+}
+
 pub fn find_eth_per_token(
     ord: u64,
     pool_address: &String,
@@ -77,28 +109,31 @@ pub fn find_eth_per_token(
     pools_store: &StoreGetProto<Pool>,
     pool_liquidities_store: &StoreGetBigInt,
     tokens_whitelist_pools_store: &StoreGetRaw,
+    token_pools_store: &StoreGetRaw,
     total_native_amounts_store: &StoreGetBigDecimal,
-    prices_store: &StoreGetBigDecimal,
-) -> BigDecimal {
+    prices_store: &PoolPrices,
+    config: &PricingConfig,
+) -> EthPerToken {
     log::debug!("finding ETH per token for {} in pool {}", token_address, pool_address);
-    if token_address.eq(WETH_ADDRESS) {
+    let wrapped_native = &config.wrapped_native;
+    if token_address.eq(wrapped_native) {
         log::debug!("is ETH return 1");
-        return BigDecimal::one();
+        return EthPerToken::unsourced(BigDecimal::one());
     }
 
-    let mut price_so_far = BigDecimal::zero();
+    let mut price_so_far = EthPerToken::unsourced(BigDecimal::zero());
 
-    if STABLE_COINS.contains(&token_address.as_str()) {
+    if config.is_stable_coin(token_address) {
         log::debug!("token addr: {} is a stable coin", token_address);
-        let eth_price_usd = get_eth_price_in_usd(prices_store, ord);
+        let eth_price_usd = get_eth_price_in_usd(prices_store, pool_liquidities_store, ord, config);
         log::info!("eth_price_usd {}", eth_price_usd);
-        price_so_far = math::safe_div(&BigDecimal::one(), &eth_price_usd);
+        price_so_far = EthPerToken::unsourced(math::safe_div(&BigDecimal::one(), &eth_price_usd));
     } else {
         // TODO: @eduard change this once the changes for store of list has been merged
         let wl = match tokens_whitelist_pools_store.get_last(&format!("token:{token_address}")) {
             None => {
                 log::debug!("failed to get whitelisted pools for token {}", token_address);
-                return BigDecimal::zero();
+                String::new()
             }
             Some(bytes) => String::from_utf8(bytes.to_vec()).unwrap(),
         };
@@ -112,7 +147,7 @@ pub fn find_eth_per_token(
         log::debug!("found whitelisted pools {}", whitelisted_pools.len());
 
         let mut largest_eth_locked = BigDecimal::zero();
-        let minimum_eth_locked = BigDecimal::from_str("52").unwrap();
+        let minimum_eth_locked = &config.minimum_native_locked;
         let mut eth_locked: BigDecimal;
 
         for pool_address in whitelisted_pools.iter() {
@@ -153,14 +188,14 @@ pub fn find_eth_per_token(
 
                     let token1_eth_price;
                     // If the counter token is WETH we know the derived price is 1
-                    if token1.address.eq(WETH_ADDRESS) {
+                    if token1.address.eq(wrapped_native) {
                         log::debug!("token 1 is WETH");
                         eth_locked = native_amount;
                         token1_eth_price = BigDecimal::one();
                     } else {
                         log::debug!("token 1 is NOT WETH");
 
-                        match pool_liquidities_store.get_at(ord, format!("pair:{WETH_ADDRESS}:{token1_addr}")) {
+                        match pool_liquidities_store.get_at(ord, format!("pair:{wrapped_native}:{token1_addr}")) {
                             None => {
                                 log::debug!("unable to find liquidity for {:?}", token1_addr);
                                 continue;
@@ -176,14 +211,14 @@ pub fn find_eth_per_token(
                             }
                         }
 
-                        token1_eth_price = match prices_store.get_at(ord, format!("pair:{WETH_ADDRESS}:{token1_addr}"))
-                        {
-                            None => {
-                                log::debug!("unable to find token 1 price in eth {token1_addr}");
-                                continue;
-                            }
-                            Some(price) => price,
-                        };
+                        token1_eth_price =
+                            match prices_store.get_at(ord, format!("pair:{wrapped_native}:{token1_addr}")) {
+                                None => {
+                                    log::debug!("unable to find token 1 price in eth {token1_addr}");
+                                    continue;
+                                }
+                                Some(price) => price,
+                            };
                         log::debug!("token 1 is price in eth {}", token1_eth_price);
                         eth_locked = native_amount.mul(token1_eth_price.clone());
                         log::debug!("computed eth locked {}", eth_locked);
@@ -195,7 +230,7 @@ pub fn find_eth_per_token(
                     );
                     // should the check below make more sens if we EITHER have eth.gt > largest && (eth_locked > min BUT !Whitelist || whitelist)???
                     if eth_locked.gt(&largest_eth_locked)
-                        && (eth_locked.gt(&minimum_eth_locked) || WHITELIST_TOKENS.contains(&token0_addr.as_str()))
+                        && (eth_locked.gt(minimum_eth_locked) || config.is_whitelisted(token0_addr))
                     {
                         log::debug!("eth locked passed test");
                         let token1_price =
@@ -208,8 +243,13 @@ pub fn find_eth_per_token(
                             };
                         log::debug!("found token 1 price {}", token1_price);
                         largest_eth_locked = eth_locked.clone();
-                        price_so_far = token1_price.mul(token1_eth_price.clone());
-                        log::debug!("price_so_far {}", price_so_far);
+                        price_so_far = EthPerToken {
+                            price: token1_price.mul(token1_eth_price.clone()),
+                            source_pool: pool_address.to_string(),
+                            eth_locked: eth_locked.clone(),
+                            hops: if token1.address.eq(wrapped_native) { 1 } else { 2 },
+                        };
+                        log::debug!("price_so_far {}", price_so_far.price);
                     }
                 }
                 if &token1.address == token_address {
@@ -229,13 +269,13 @@ pub fn find_eth_per_token(
                     let mut token0_eth_price = BigDecimal::zero();
 
                     // If the counter token is WETH we know the derived price is 1
-                    if token0.address.eq(WETH_ADDRESS) {
+                    if token0.address.eq(wrapped_native) {
                         log::debug!("token 0 is WETH");
                         eth_locked = native_amount
                     } else {
                         log::debug!("token 0 is NOT WETH");
 
-                        match pool_liquidities_store.get_at(ord, format!("pair:{WETH_ADDRESS}:{token0_addr}")) {
+                        match pool_liquidities_store.get_at(ord, format!("pair:{wrapped_native}:{token0_addr}")) {
                             None => {
                                 log::debug!("unable to find liquidity for {:?}", token0_addr);
                                 continue;
@@ -251,21 +291,21 @@ pub fn find_eth_per_token(
                             }
                         }
 
-                        token0_eth_price = match prices_store.get_at(ord, format!("pair:{WETH_ADDRESS}:{token0_addr}"))
-                        {
-                            None => {
-                                log::debug!("unable to find token 0 price in eth {:?}", token0.address);
-                                continue;
-                            }
-                            Some(price) => price,
-                        };
+                        token0_eth_price =
+                            match prices_store.get_at(ord, format!("pair:{wrapped_native}:{token0_addr}")) {
+                                None => {
+                                    log::debug!("unable to find token 0 price in eth {:?}", token0.address);
+                                    continue;
+                                }
+                                Some(price) => price,
+                            };
                         log::debug!("token 0 is price in eth {}", token0_eth_price);
                         eth_locked = native_amount.mul(token0_eth_price.clone());
                         log::debug!("computed eth locked {}", eth_locked);
                     }
                     log::debug!("eth locked in pool {pool_address} {eth_locked} (largest {largest_eth_locked})",);
                     if eth_locked.gt(&largest_eth_locked)
-                        && (eth_locked.gt(&minimum_eth_locked) || WHITELIST_TOKENS.contains(&token1_addr.as_str()))
+                        && (eth_locked.gt(minimum_eth_locked) || config.is_whitelisted(token1_addr))
                     {
                         log::debug!("eth locked passed test");
                         let token0_price =
@@ -278,19 +318,282 @@ pub fn find_eth_per_token(
                             };
                         log::debug!("found token 0 price {}", token0_price);
                         largest_eth_locked = eth_locked.clone();
-                        price_so_far = token0_price.mul(token0_eth_price.clone());
-                        log::debug!("price_so_far {}", price_so_far);
+                        price_so_far = EthPerToken {
+                            price: token0_price.mul(token0_eth_price.clone()),
+                            source_pool: pool_address.to_string(),
+                            eth_locked: eth_locked.clone(),
+                            hops: if token0.address.eq(wrapped_native) { 1 } else { 2 },
+                        };
+                        log::debug!("price_so_far {}", price_so_far.price);
                     }
                 }
             }
         }
+
+        if price_so_far.price.eq(&BigDecimal::zero()) {
+            log::debug!(
+                "no whitelisted pool prices token {token_address}, searching a path to the wrapped native token"
+            );
+            let pool_graph = StorePoolGraph {
+                ord,
+                pools_store,
+                pool_liquidities_store,
+                token_pools_store,
+                total_native_amounts_store,
+                prices_store,
+            };
+            if let Some(path_price) =
+                find_eth_per_token_through_paths(token_address, &pool_graph, config, MAX_PRICING_POOL_LOOKUPS)
+            {
+                price_so_far = path_price;
+            }
+        }
     }
     return price_so_far;
 }
 
-pub fn get_eth_price_in_usd(prices_store: &StoreGetBigDecimal, ordinal: u64) -> BigDecimal {
-    let key = format!("pool:{}:{}:{}", USDC_WETH_03_POOL, USDC_ADDRESS, "token0");
-    return match prices_store.get_at(ordinal, &key) {
+// bounds the number of pools read by a path search, tokens like USDC are in thousands of pools. The search runs on
+// every price update of a token no whitelisted pool prices
+const MAX_PRICING_POOL_LOOKUPS: usize = 200;
+
+/// Pools read by a pricing path search
+trait PoolGraph {
+    /// pools the token is in
+    fn token_pools(&self, token: &str) -> Vec<String>;
+    /// tokens of a pool, None when it has no liquidity
+    fn pool_tokens(&self, pool_address: &str) -> Option<(String, String)>;
+    /// price of the other token of the pool in `token`, its token0 or token1
+    fn price(&self, pool_address: &str, token: &str, token_idx: u8) -> Option<BigDecimal>;
+    /// amount of `token` locked in the pool, valued in the native token
+    fn native_amount(&self, pool_address: &str, token: &str) -> Option<BigDecimal>;
+}
+
+struct StorePoolGraph<'a> {
+    ord: u64,
+    pools_store: &'a StoreGetProto<Pool>,
+    pool_liquidities_store: &'a StoreGetBigInt,
+    token_pools_store: &'a StoreGetRaw,
+    total_native_amounts_store: &'a StoreGetBigDecimal,
+    prices_store: &'a PoolPrices<'a>,
+}
+
+impl PoolGraph for StorePoolGraph<'_> {
+    fn token_pools(&self, token: &str) -> Vec<String> {
+        match self.token_pools_store.get_last(&format!("token:{token}")) {
+            None => vec![],
+            Some(bytes) => String::from_utf8(bytes.to_vec())
+                .unwrap()
+                .split(";")
+                .filter(|p| !p.is_empty())
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+
+    fn pool_tokens(&self, pool_address: &str) -> Option<(String, String)> {
+        let pool_liquidity = self
+            .pool_liquidities_store
+            .get_at(self.ord, format!("pool:{pool_address}"))?;
+        if pool_liquidity.le(&BigInt::zero()) {
+            return None;
+        }
+        let pool = self.pools_store.get_last(format!("pool:{pool_address}"))?;
+        Some((
+            pool.token0.as_ref().unwrap().address.clone(),
+            pool.token1.as_ref().unwrap().address.clone(),
+        ))
+    }
+
+    fn price(&self, pool_address: &str, token: &str, token_idx: u8) -> Option<BigDecimal> {
+        self.prices_store
+            .get_at(self.ord, format!("pool:{pool_address}:{token}:token{token_idx}"))
+    }
+
+    fn native_amount(&self, pool_address: &str, token: &str) -> Option<BigDecimal> {
+        self.total_native_amounts_store
+            .get_at(self.ord, format!("pool:{pool_address}:{token}:native"))
+    }
+}
+
+struct PricingPath {
+    /// last token of the path
+    token: String,
+    /// tokens of the path, a path never goes through the same token twice
+    tokens: Vec<String>,
+    /// pools of the path and the price of each one's input token in its output token
+    pools: Vec<String>,
+    rates: Vec<BigDecimal>,
+    /// amount of the last token for one priced token
+    price: BigDecimal,
+    /// amount locked in the shallowest pool of the path, valued in the priced token, None for the empty path
+    depth: Option<BigDecimal>,
+}
+
+/// Derives the ETH price of a token from the deepest path of at most `max_pricing_hops` pools
+/// leading to the wrapped native token. Like the whitelisted pools, each pool is valued by the amount of its
+/// token closest to the wrapped native token, the depth of a path being the value of its shallowest pool,
+/// and a path less deep than `minimum_native_locked` ETH is not used.
+fn find_eth_per_token_through_paths<G: PoolGraph>(
+    token_address: &String,
+    pool_graph: &G,
+    config: &PricingConfig,
+    max_lookups: usize,
+) -> Option<EthPerToken> {
+    let wrapped_native = &config.wrapped_native;
+    let mut deepest_depths: HashMap<String, BigDecimal> = HashMap::new();
+    let mut native_paths: Vec<PricingPath> = vec![];
+    let mut lookups = 0;
+
+    let mut paths = vec![PricingPath {
+        token: token_address.clone(),
+        tokens: vec![token_address.clone()],
+        pools: vec![],
+        rates: vec![],
+        price: BigDecimal::one(),
+        depth: None,
+    }];
+
+    'search: for _ in 0..config.max_pricing_hops {
+        let mut next_paths = vec![];
+        for path in paths.iter() {
+            for pool_address in pool_graph.token_pools(&path.token).iter() {
+                if lookups == max_lookups {
+                    log::info!("pricing path search for token {token_address} stopped after {lookups} pools");
+                    break 'search;
+                }
+                lookups += 1;
+
+                let (token0_addr, token1_addr) = match pool_graph.pool_tokens(pool_address) {
+                    None => continue,
+                    Some(tokens) => tokens,
+                };
+
+                // price of the current token in the next token of the path
+                let (next_token, next_token_idx) = if token0_addr == path.token {
+                    (&token1_addr, 1)
+                } else if token1_addr == path.token {
+                    (&token0_addr, 0)
+                } else {
+                    continue;
+                };
+                if path.tokens.contains(next_token) {
+                    continue;
+                }
+
+                let price = match pool_graph.price(pool_address, next_token, next_token_idx) {
+                    None => continue,
+                    Some(price) => price,
+                };
+                let next_price = path.price.clone().mul(price.clone());
+                if next_price.le(&BigDecimal::zero()) {
+                    continue;
+                }
+
+                // the amount of the next token locked in the pool, valued in the priced token
+                let native_amount = match pool_graph.native_amount(pool_address, next_token) {
+                    None => continue,
+                    Some(amount) => amount,
+                };
+                let pool_depth = native_amount.div(next_price.clone());
+                let depth = match &path.depth {
+                    Some(depth) if depth.lt(&pool_depth) => depth.clone(),
+                    _ => pool_depth,
+                };
+                if let Some(deepest_depth) = deepest_depths.get(next_token) {
+                    if deepest_depth.ge(&depth) {
+                        continue;
+                    }
+                }
+
+                let mut tokens = path.tokens.clone();
+                tokens.push(next_token.clone());
+                let mut pools = path.pools.clone();
+                pools.push(pool_address.to_string());
+                let mut rates = path.rates.clone();
+                rates.push(price.clone());
+                let next_path = PricingPath {
+                    token: next_token.clone(),
+                    tokens,
+                    pools,
+                    rates,
+                    price: next_price,
+                    depth: Some(depth.clone()),
+                };
+
+                deepest_depths.insert(next_token.clone(), depth);
+                if next_token == wrapped_native {
+                    native_paths.push(next_path);
+                    continue;
+                }
+                next_paths.push(next_path);
+            }
+        }
+        paths = next_paths;
+    }
+
+    // the paths give different prices, so their depths are compared in ETH
+    let mut deepest_path: Option<(PricingPath, BigDecimal)> = None;
+    for path in native_paths {
+        let depth_eth = path.depth.clone().unwrap().mul(path.price.clone());
+        if depth_eth.le(&config.minimum_native_locked) {
+            continue;
+        }
+        let deeper = match &deepest_path {
+            Some((_, deepest_depth_eth)) => depth_eth.gt(deepest_depth_eth),
+            None => true,
+        };
+        if deeper {
+            deepest_path = Some((path, depth_eth));
+        }
+    }
+    let path = match deepest_path {
+        None => {
+            log::debug!("no pricing path deep enough found for token {token_address}");
+            return None;
+        }
+        Some((path, _)) => path,
+    };
+    log::debug!("token {token_address} priced through {}", path.tokens.join(" -> "));
+
+    // ETH locked by the counter token in the first pool, valued through the rest of the path
+    let source_pool = &path.pools[0];
+    let counter_token = &path.tokens[1];
+    let counter_token_eth_price = path.rates[1..]
+        .iter()
+        .fold(BigDecimal::one(), |price, rate| price.mul(rate.clone()));
+    let eth_locked = match pool_graph.native_amount(source_pool, counter_token) {
+        None => BigDecimal::zero(),
+        Some(amount) => amount.mul(counter_token_eth_price),
+    };
+
+    Some(EthPerToken {
+        source_pool: source_pool.clone(),
+        eth_locked,
+        hops: path.pools.len() as u32,
+        price: path.price,
+    })
+}
+
+pub struct EthPriceSource {
+    pub pool: String,
+    pub price: BigDecimal,
+    /// false when the reference pool has no in-range liquidity at this ordinal
+    pub included: bool,
+}
+
+pub fn get_eth_price_in_usd(
+    prices_store: &PoolPrices,
+    pool_liquidities_store: &StoreGetBigInt,
+    ordinal: u64,
+    config: &PricingConfig,
+) -> BigDecimal {
+    let prices: Vec<BigDecimal> = eth_price_sources(prices_store, pool_liquidities_store, ordinal, config)
+        .into_iter()
+        .filter(|source| source.included)
+        .map(|source| source.price)
+        .collect();
+
+    return match median(prices) {
         None => {
             log::debug!("price not found");
             BigDecimal::zero()
@@ -298,3 +601,259 @@ pub fn get_eth_price_in_usd(prices_store: &StoreGetBigDecimal, ordinal: u64) ->
         Some(price) => price,
     };
 }
+
+pub fn eth_price_sources(
+    prices_store: &PoolPrices,
+    pool_liquidities_store: &StoreGetBigInt,
+    ordinal: u64,
+    config: &PricingConfig,
+) -> Vec<EthPriceSource> {
+    let mut sources = vec![];
+    for reference_pool in &config.reference_pools {
+        // the price of the stable token is keyed by its position in the reference pool
+        let pool = &reference_pool.pool;
+        let stable_token = &reference_pool.stable_token;
+        let price = match prices_store
+            .get_at(ordinal, format!("pool:{pool}:{stable_token}:token0"))
+            .or_else(|| prices_store.get_at(ordinal, format!("pool:{pool}:{stable_token}:token1")))
+        {
+            None => continue,
+            Some(price) => price,
+        };
+
+        let included = match pool_liquidities_store.get_at(ordinal, format!("pool:{pool}")) {
+            None => false,
+            Some(liquidity) => liquidity.gt(&BigInt::zero()),
+        };
+
+        sources.push(EthPriceSource {
+            pool: pool.clone(),
+            price,
+            included,
+        });
+    }
+    sources
+}
+
+// A median rather than a liquidity weighted average: the raw liquidity of pools
+// whose stable tokens have different decimals isn't comparable.
+fn median(mut prices: Vec<BigDecimal>) -> Option<BigDecimal> {
+    if prices.is_empty() {
+        return None;
+    }
+
+    prices.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let middle = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        return Some(prices[middle].clone());
+    }
+
+    Some((prices[middle - 1].clone() + prices[middle].clone()).div(BigDecimal::from(2)))
+}
+
+/// A price moving by more than `max_jump` times, up or down, from its previous block value
+/// is considered a single block manipulation (sandwich, flash loan) rather than a market move.
+pub fn is_price_outlier(previous_price: &BigDecimal, price: &BigDecimal, max_jump: &BigDecimal) -> bool {
+    if previous_price.eq(&BigDecimal::zero()) || price.eq(&BigDecimal::zero()) {
+        return false;
+    }
+
+    let jump = match price.gt(previous_price) {
+        true => math::safe_div(price, previous_price),
+        false => math::safe_div(previous_price, price),
+    };
+    jump.gt(max_jump)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PricingConfig;
+    use crate::price::{find_eth_per_token_through_paths, is_price_outlier, median, PoolGraph};
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use substreams::scalar::BigDecimal;
+
+    fn prices(values: &[&str]) -> Vec<BigDecimal> {
+        values
+            .iter()
+            .map(|value| BigDecimal::from_str(value).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn median_of_prices() {
+        assert_eq!(None, median(vec![]));
+        assert_eq!(Some(BigDecimal::from(1800)), median(prices(&["1800"])));
+        assert_eq!(Some(BigDecimal::from(1801)), median(prices(&["1803", "1799", "1801"])));
+        assert_eq!(
+            Some(BigDecimal::from_str("1800.5").unwrap()),
+            median(prices(&["1802", "1799", "1801", "1800"]))
+        );
+    }
+
+    #[test]
+    fn price_outliers() {
+        let max_jump = BigDecimal::from(5);
+        let previous_price = BigDecimal::from(2000);
+
+        assert!(!is_price_outlier(&previous_price, &BigDecimal::from(2500), &max_jump));
+        assert!(!is_price_outlier(&previous_price, &BigDecimal::from(10000), &max_jump));
+        assert!(is_price_outlier(&previous_price, &BigDecimal::from(10001), &max_jump));
+        assert!(is_price_outlier(&previous_price, &BigDecimal::from(300), &max_jump));
+        assert!(!is_price_outlier(
+            &BigDecimal::zero(),
+            &BigDecimal::from(300),
+            &max_jump
+        ));
+    }
+
+    struct MemoryPool {
+        token0: String,
+        token1: String,
+        /// price of token0 in token1
+        price: BigDecimal,
+        native_amount0: BigDecimal,
+        native_amount1: BigDecimal,
+    }
+
+    #[derive(Default)]
+    struct MemoryPoolGraph {
+        pools: HashMap<String, MemoryPool>,
+        token_pools: HashMap<String, Vec<String>>,
+    }
+
+    impl MemoryPoolGraph {
+        fn pool(
+            mut self,
+            address: &str,
+            token0: &str,
+            token1: &str,
+            price: i64,
+            native_amount0: i64,
+            native_amount1: i64,
+        ) -> Self {
+            for token in [token0, token1] {
+                self.token_pools
+                    .entry(token.to_string())
+                    .or_default()
+                    .push(address.to_string());
+            }
+            self.pools.insert(
+                address.to_string(),
+                MemoryPool {
+                    token0: token0.to_string(),
+                    token1: token1.to_string(),
+                    price: BigDecimal::from(price),
+                    native_amount0: BigDecimal::from(native_amount0),
+                    native_amount1: BigDecimal::from(native_amount1),
+                },
+            );
+            self
+        }
+
+        // a pool without liquidity
+        fn empty_pool(mut self, address: &str, token: &str) -> Self {
+            self.token_pools
+                .entry(token.to_string())
+                .or_default()
+                .push(address.to_string());
+            self
+        }
+    }
+
+    impl PoolGraph for MemoryPoolGraph {
+        fn token_pools(&self, token: &str) -> Vec<String> {
+            self.token_pools.get(token).cloned().unwrap_or_default()
+        }
+
+        fn pool_tokens(&self, pool_address: &str) -> Option<(String, String)> {
+            let pool = self.pools.get(pool_address)?;
+            Some((pool.token0.clone(), pool.token1.clone()))
+        }
+
+        fn price(&self, pool_address: &str, _token: &str, token_idx: u8) -> Option<BigDecimal> {
+            let pool = self.pools.get(pool_address)?;
+            match token_idx {
+                1 => Some(pool.price.clone()),
+                _ => Some(BigDecimal::one() / pool.price.clone()),
+            }
+        }
+
+        fn native_amount(&self, pool_address: &str, token: &str) -> Option<BigDecimal> {
+            let pool = self.pools.get(pool_address)?;
+            if pool.token0 == token {
+                Some(pool.native_amount0.clone())
+            } else {
+                Some(pool.native_amount1.clone())
+            }
+        }
+    }
+
+    fn pricing_config(max_pricing_hops: usize) -> PricingConfig {
+        PricingConfig {
+            wrapped_native: "eth".to_string(),
+            minimum_native_locked: BigDecimal::from(10),
+            max_pricing_hops,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn path_within_the_hops() {
+        let graph = MemoryPoolGraph::default()
+            .pool("ab", "a", "b", 2, 100, 100)
+            .pool("bc", "b", "c", 3, 100, 100)
+            .pool("c-eth", "c", "eth", 4, 100, 100);
+
+        let eth_per_token =
+            find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(3), 1000).unwrap();
+        assert_eq!(BigDecimal::from(24), eth_per_token.price);
+        assert_eq!("ab", eth_per_token.source_pool);
+        assert_eq!(3, eth_per_token.hops);
+
+        assert!(find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(2), 1000).is_none());
+    }
+
+    #[test]
+    fn deepest_path_in_eth() {
+        // the direct pool holds 20 ETH, the path through b at least 100 ETH in each pool
+        let graph = MemoryPoolGraph::default()
+            .pool("a-eth", "a", "eth", 2, 10, 20)
+            .pool("ab", "a", "b", 1, 100, 100)
+            .pool("b-eth", "b", "eth", 2, 100, 100);
+
+        let eth_per_token =
+            find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(3), 1000).unwrap();
+        assert_eq!("ab", eth_per_token.source_pool);
+        assert_eq!(2, eth_per_token.hops);
+        assert_eq!(BigDecimal::from(2), eth_per_token.price);
+        // the b locked in the source pool valued through the rest of the path
+        assert_eq!(BigDecimal::from(200), eth_per_token.eth_locked);
+
+        // the shallower path is used when it is the only one
+        let eth_per_token =
+            find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(1), 1000).unwrap();
+        assert_eq!("a-eth", eth_per_token.source_pool);
+    }
+
+    #[test]
+    fn minimum_native_locked() {
+        let shallow = MemoryPoolGraph::default().pool("a-eth", "a", "eth", 2, 10, 10);
+        assert!(find_eth_per_token_through_paths(&"a".to_string(), &shallow, &pricing_config(3), 1000).is_none());
+
+        let deep = MemoryPoolGraph::default().pool("a-eth", "a", "eth", 2, 10, 11);
+        assert!(find_eth_per_token_through_paths(&"a".to_string(), &deep, &pricing_config(3), 1000).is_some());
+    }
+
+    #[test]
+    fn lookups_cap() {
+        let mut graph = MemoryPoolGraph::default();
+        for i in 0..5 {
+            graph = graph.empty_pool(&format!("empty{i}"), "a");
+        }
+        let graph = graph.pool("a-eth", "a", "eth", 2, 100, 100);
+
+        assert!(find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(3), 5).is_none());
+        assert!(find_eth_per_token_through_paths(&"a".to_string(), &graph, &pricing_config(3), 6).is_some());
+    }
+}
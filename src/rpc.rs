@@ -1,11 +1,11 @@
-use crate::{abi, eth, utils, Erc20Token};
-use prost::Message;
+use crate::config::NetworkConfig;
+use crate::{abi, eth, Erc20Token};
 use substreams::log;
 use substreams::scalar::BigInt;
 use substreams::Hex;
 use substreams_ethereum::rpc::RpcBatch;
 
-pub fn create_uniswap_token(token_address: &String) -> Option<Erc20Token> {
+pub fn create_uniswap_token(token_address: &String, config: &NetworkConfig) -> Option<Erc20Token> {
     let batch = RpcBatch::new();
     let responses = batch
         .add(abi::erc20::functions::Decimals {}, hex::decode(token_address).unwrap())
@@ -20,7 +20,7 @@ pub fn create_uniswap_token(token_address: &String) -> Option<Erc20Token> {
         Some(decoded_decimals) => {
             decimals = decoded_decimals.to_u64();
         }
-        None => match utils::get_static_uniswap_tokens(token_address.encode_to_vec().as_slice()) {
+        None => match config.static_token(token_address) {
             Some(token) => decimals = token.decimals,
             None => {
                 log::debug!(
@@ -39,8 +39,8 @@ pub fn create_uniswap_token(token_address: &String) -> Option<Erc20Token> {
         Some(decoded_name) => {
             name = decoded_name;
         }
-        None => match utils::get_static_uniswap_tokens(token_address.encode_to_vec().as_slice()) {
-            Some(token) => name = token.name,
+        None => match config.static_token(token_address) {
+            Some(token) => name = token.name.clone(),
             None => {
                 log::debug!(
                     "{} is not a an ERC20 token contract name `eth_call` failed",
@@ -57,8 +57,8 @@ pub fn create_uniswap_token(token_address: &String) -> Option<Erc20Token> {
         Some(decoded_symbol) => {
             symbol = decoded_symbol;
         }
-        None => match utils::get_static_uniswap_tokens(token_address.encode_to_vec().as_slice()) {
-            Some(token) => symbol = token.symbol,
+        None => match config.static_token(token_address) {
+            Some(token) => symbol = token.symbol.clone(),
             None => {
                 log::debug!(
                     "{} is not a an ERC20 token contract symbol `eth_call` failed",
@@ -80,6 +80,18 @@ pub fn create_uniswap_token(token_address: &String) -> Option<Erc20Token> {
     });
 }
 
+/// The `PoolCreated` event of a pool rebuilt from its immutables, for the pools created without one
+pub fn pool_created_call(pool_address: &String) -> Option<abi::factory::events::PoolCreated> {
+    let address = hex::decode(pool_address).unwrap();
+    Some(abi::factory::events::PoolCreated {
+        token0: abi::pool::functions::Token0 {}.call(address.clone())?,
+        token1: abi::pool::functions::Token1 {}.call(address.clone())?,
+        fee: abi::pool::functions::Fee {}.call(address.clone())?,
+        tick_spacing: abi::pool::functions::TickSpacing {}.call(address.clone())?,
+        pool: address,
+    })
+}
+
 pub fn token_total_supply_call(token_address: &String) -> Option<BigInt> {
     let token_supply = abi::erc20::functions::TotalSupply {};
     if let Some(token_supply_result) = token_supply.call(hex::decode(token_address).unwrap()) {
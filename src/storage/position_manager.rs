@@ -149,6 +149,11 @@ impl<'a> PositionStruct<'a> {
         }
     }
 
+    // the address approved for the token, packed with the nonce
+    pub fn operator(&self) -> Option<([u8; 20], [u8; 20])> {
+        self.address()
+    }
+
     pub fn pool_id(&self) -> Option<(BigInt, BigInt)> {
         let slot = BigInt::from(1);
         let offset = 0;
@@ -262,6 +267,44 @@ impl<'a> PositionStruct<'a> {
             None
         }
     }
+
+    pub fn tokens_owed0(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(4);
+        let offset = 0;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn tokens_owed1(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(4);
+        let offset = 16;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
 }
 
 pub struct PoolKeyStruct<'a> {
@@ -381,6 +424,30 @@ mod tests {
         assert_eq!(None, v_opt);
     }
 
+    #[test]
+    fn position_operator_approved() {
+        let changes = vec![StorageChange {
+            address: hex!("C36442b4a4522E871399CD717aBDD847Ab11FE88").to_vec(),
+            key: hex!("d421a5181c571bba3f01190c922c3b2a896fc1d84e86c9f17ac10e67ebef8b5c").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("11e4857bb9993a50c685a79afad4e6f65d518dda000000000000000000000001").to_vec(),
+            ordinal: 0,
+        }];
+        let storage = get_position_manager(&changes);
+        let position = storage.positions(&BigInt::from_str("1").unwrap());
+        assert_eq!(
+            Some((
+                hex!("0000000000000000000000000000000000000000"),
+                hex!("11e4857bb9993a50c685a79afad4e6f65d518dda"),
+            )),
+            position.operator()
+        );
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1").unwrap())),
+            position.nonce()
+        );
+    }
+
     #[test]
     fn position_pool_id() {
         let changes = get_store_changes();
@@ -448,6 +515,38 @@ mod tests {
         assert_eq!(None, v_opt);
     }
 
+    #[test]
+    fn position_tokens_owed() {
+        let changes = get_store_changes();
+        let storage = get_position_manager(&changes);
+        let position = storage.positions(&BigInt::from_str("1").unwrap());
+        assert_eq!(None, position.tokens_owed0());
+        assert_eq!(None, position.tokens_owed1());
+    }
+
+    #[test]
+    fn position_tokens_owed_after_decrease() {
+        // tokensOwed1 in the high order bytes and tokensOwed0 in the low order ones
+        let changes = vec![StorageChange {
+            address: hex!("C36442b4a4522E871399CD717aBDD847Ab11FE88").to_vec(),
+            key: hex!("d421a5181c571bba3f01190c922c3b2a896fc1d84e86c9f17ac10e67ebef8b60").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("00000000000000000000000000000007000000000000000000000000000003e8").to_vec(),
+            ordinal: 0,
+        }];
+        let storage = get_position_manager(&changes);
+        let position = storage.positions(&BigInt::from_str("1").unwrap());
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1000").unwrap())),
+            position.tokens_owed0()
+        );
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("7").unwrap())),
+            position.tokens_owed1()
+        );
+        assert_eq!(None, position.liquidity());
+    }
+
     #[test]
     fn pool_ids() {
         let changes = get_store_changes();
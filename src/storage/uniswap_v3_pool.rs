@@ -1,8 +1,12 @@
 use crate::storage::utils;
 use hex::encode;
+use std::collections::HashSet;
 use substreams::scalar::BigInt;
 use substreams_ethereum::pb::eth::v2::StorageChange;
 
+const OBSERVATIONS_SLOT: u64 = 8;
+const OBSERVATIONS_LENGTH: u64 = 65535;
+
 pub struct UniswapPoolStorage<'a> {
     pub storage_changes: &'a Vec<StorageChange>,
     pub contract_addr: [u8; 20],
@@ -58,6 +62,50 @@ impl<'a> UniswapPoolStorage<'a> {
         }
     }
 
+    // the amount of token0 protocol fees owed to the factory owner, packed with token1 in the `protocolFees` slot
+    pub fn protocol_fees_token0(&self) -> Option<(BigInt, BigInt)> {
+        let protocol_fees_slot = BigInt::from(3);
+        let offset = 0;
+        let number_of_bytes = 16;
+
+        // ----
+        let slot_key = utils::left_pad_from_bigint(&protocol_fees_slot);
+        // ----
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.filtered_changes(), slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // the amount of token1 protocol fees owed to the factory owner
+    pub fn protocol_fees_token1(&self) -> Option<(BigInt, BigInt)> {
+        let protocol_fees_slot = BigInt::from(3);
+        let offset = 16;
+        let number_of_bytes = 16;
+
+        // ----
+        let slot_key = utils::left_pad_from_bigint(&protocol_fees_slot);
+        // ----
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.filtered_changes(), slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
     pub fn liquidity(&self) -> Option<(BigInt, BigInt)> {
         let liquidity_slot = BigInt::from(4);
         let offset = 0;
@@ -90,6 +138,68 @@ impl<'a> UniswapPoolStorage<'a> {
         return TickStruct::new(self.filtered_changes(), ticker_struct_slot);
     }
 
+    // the tickBitmap map is keyed by the int16 word position, the word being a uint256 decoded as signed
+    pub fn tick_bitmap(&self, word_pos: i16) -> Option<(BigInt, BigInt)> {
+        let tick_bitmap_slot = utils::left_pad_from_bigint(&BigInt::from(6));
+        let slot_key = utils::calc_map_slot(
+            &utils::left_pad_from_bigint(&BigInt::from(word_pos as i32)),
+            &tick_bitmap_slot,
+        );
+
+        if let Some((old_data, new_data)) = utils::get_storage_change(&self.filtered_changes(), slot_key, 0, 32) {
+            Some((
+                BigInt::from_signed_bytes_be(old_data),
+                BigInt::from_signed_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self, owner: &Vec<u8>, tick_lower: i32, tick_upper: i32) -> PositionInfoStruct {
+        let positions_slot = utils::left_pad_from_bigint(&BigInt::from(7));
+        let position_key = utils::position_key(owner, tick_lower, tick_upper);
+        let position_struct_slot = utils::calc_map_slot(&position_key, &positions_slot);
+        return PositionInfoStruct::new(self.filtered_changes(), position_struct_slot);
+    }
+
+    pub fn observations(&self, index: u32) -> ObservationStruct {
+        let observation_slot = utils::left_pad_from_bigint(&BigInt::from(OBSERVATIONS_SLOT + index as u64));
+        return ObservationStruct::new(self.filtered_changes(), observation_slot);
+    }
+
+    // the observations array is a fixed size array stored from slot 8, one slot per observation,
+    // so the written indexes can be read straight out of the storage keys
+    pub fn observation_indexes(&self) -> Vec<u32> {
+        let mut indexes: Vec<u32> = self
+            .filtered_changes()
+            .iter()
+            .filter_map(|change| {
+                let slot = BigInt::from_unsigned_bytes_be(&change.key);
+                if slot.lt(&BigInt::from(OBSERVATIONS_SLOT))
+                    || slot.ge(&BigInt::from(OBSERVATIONS_SLOT + OBSERVATIONS_LENGTH))
+                {
+                    return None;
+                }
+                Some((slot.to_u64() - OBSERVATIONS_SLOT) as u32)
+            })
+            .collect();
+        indexes.sort();
+        indexes.dedup();
+        indexes
+    }
+
+    // the members of the maps are stored at hashed slots, past the pool variables and the observations array
+    pub fn changed_map_slots(&self) -> HashSet<[u8; 32]> {
+        let last_observation_slot = BigInt::from(OBSERVATIONS_SLOT + OBSERVATIONS_LENGTH - 1);
+        return self
+            .filtered_changes()
+            .into_iter()
+            .filter(|change| BigInt::from_unsigned_bytes_be(&change.key).gt(&last_observation_slot))
+            .map(|change| utils::left_pad(&change.key, 0))
+            .collect();
+    }
+
     fn filtered_changes(&self) -> Vec<&StorageChange> {
         return self
             .storage_changes
@@ -125,8 +235,8 @@ impl<'a> Slot0Struct<'a> {
             utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
         {
             Some((
-                BigInt::from_signed_bytes_be(old_data),
-                BigInt::from_signed_bytes_be(new_data),
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
             ))
         } else {
             None
@@ -166,9 +276,10 @@ impl<'a> Slot0Struct<'a> {
         if let Some((old_data, new_data)) =
             utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
         {
+            // uint16, values above 32767 must not be read as negative
             Some((
-                BigInt::from_signed_bytes_be(old_data),
-                BigInt::from_signed_bytes_be(new_data),
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
             ))
         } else {
             None
@@ -188,8 +299,8 @@ impl<'a> Slot0Struct<'a> {
             utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
         {
             Some((
-                BigInt::from_signed_bytes_be(old_data),
-                BigInt::from_signed_bytes_be(new_data),
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
             ))
         } else {
             None
@@ -209,8 +320,8 @@ impl<'a> Slot0Struct<'a> {
             utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
         {
             Some((
-                BigInt::from_signed_bytes_be(old_data),
-                BigInt::from_signed_bytes_be(new_data),
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
             ))
         } else {
             None
@@ -218,7 +329,7 @@ impl<'a> Slot0Struct<'a> {
     }
 
     // the current protocol fee as a percentage of the swap fee taken on withdrawal
-    // represented as an integer denominator (1/x)%
+    // represented as an integer denominator (1/x)%, token0 in the lower 4 bits and token1 in the upper 4 bits
     pub fn fee_protocol(&self) -> Option<(BigInt, BigInt)> {
         let slot = BigInt::zero();
         let offset = 29;
@@ -230,9 +341,10 @@ impl<'a> Slot0Struct<'a> {
         if let Some((old_data, new_data)) =
             utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
         {
+            // the packed value goes up to 0xaa, it must not be read as a signed byte
             Some((
-                BigInt::from_signed_bytes_be(old_data),
-                BigInt::from_signed_bytes_be(new_data),
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
             ))
         } else {
             None
@@ -271,6 +383,51 @@ impl<'a> TickStruct<'a> {
         };
     }
 
+    // the slots of the fee growths outside and of the oracle values outside, the members a crossing updates
+    pub fn crossing_slots(&self) -> Vec<[u8; 32]> {
+        (1..=3)
+            .map(|slot| utils::calc_struct_slot(&self.struct_slot, BigInt::from(slot)))
+            .collect()
+    }
+
+    pub fn liquidity_gross(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(0);
+        let offset = 0;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn liquidity_net(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(0);
+        let offset = 16;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_signed_bytes_be(old_data),
+                BigInt::from_signed_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
     pub fn initialized(&self) -> Option<(bool, bool)> {
         let slot = BigInt::from(3);
         let offset = 31;
@@ -324,133 +481,393 @@ impl<'a> TickStruct<'a> {
             None
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::storage::uniswap_v3_pool::UniswapPoolStorage;
-    use crate::storage::utils;
-    use std::ops::Add;
-    use std::str::FromStr;
-    use std::{fmt::Write, num::ParseIntError};
-    use substreams::scalar::BigInt;
-    use substreams::{hex, Hex};
-    use substreams_ethereum::pb::eth::v2::StorageChange;
-    use tiny_keccak::{Hasher, Keccak};
+    pub fn tick_cumulative_outside(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(3);
+        let offset = 0;
+        let number_of_bytes = 7;
 
-    #[test]
-    fn slot0_sqrt_price_x96() {
-        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
-        let storage_changes = vec![StorageChange {
-            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
-            ordinal: 0,
-        }];
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
 
-        let storage = UniswapPoolStorage::new(
-            &storage_changes,
-            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-        );
-        let v_opt = storage.slot0().sqrt_price_x96();
-        assert_eq!(
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
             Some((
-                BigInt::from_str("0").unwrap(),
-                BigInt::from_str("8927094545831003674704908909").unwrap()
-            )),
-            v_opt
-        );
+                BigInt::from_signed_bytes_be(old_data),
+                BigInt::from_signed_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn slot0_tick() {
-        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
-        let storage_changes = vec![StorageChange {
-            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
-            ordinal: 0,
-        }];
+    pub fn seconds_per_liquidity_outside_x128(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(3);
+        let offset = 7;
+        let number_of_bytes = 20;
 
-        let storage = UniswapPoolStorage::new(
-            &storage_changes,
-            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-        );
-        let v_opt = storage.slot0().tick();
-        assert_eq!(
-            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("-43667").unwrap())),
-            v_opt
-        );
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
     }
 
-    #[test]
-    fn slot0_observation_index() {
-        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
-        let storage_changes = vec![StorageChange {
-            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
-            ordinal: 0,
-        }];
+    pub fn seconds_outside(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(3);
+        let offset = 27;
+        let number_of_bytes = 4;
 
-        let storage = UniswapPoolStorage::new(
-            &storage_changes,
-            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-        );
-        let v_opt = storage.slot0().observation_index();
-        // going from 0 to 0 yields no cahnge
-        assert_eq!(None, v_opt);
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
     }
+}
 
-    #[test]
-    fn slot0_observation_cardinality() {
-        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
-        let storage_changes = vec![StorageChange {
-            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
-            ordinal: 0,
-        }];
+pub struct PositionInfoStruct<'a> {
+    pub storage_changes: Vec<&'a StorageChange>,
+    pub struct_slot: [u8; 32],
+}
 
-        let storage = UniswapPoolStorage::new(
-            &storage_changes,
-            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-        );
-        let v_opt = storage.slot0().observation_cardinality();
-        assert_eq!(
-            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1").unwrap())),
-            v_opt
-        );
+impl<'a> PositionInfoStruct<'a> {
+    pub fn new(storage_changes: Vec<&'a StorageChange>, struct_slot: [u8; 32]) -> PositionInfoStruct<'a> {
+        return Self {
+            struct_slot: struct_slot,
+            storage_changes: storage_changes,
+        };
     }
 
-    #[test]
-    fn slot0_observation_cardinality_next() {
-        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
-        let storage_changes = vec![StorageChange {
-            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
-            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
-            ordinal: 0,
-        }];
+    pub fn liquidity(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(0);
+        let offset = 0;
+        let number_of_bytes = 16;
 
-        let storage = UniswapPoolStorage::new(
-            &storage_changes,
-            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
-        );
-        let v_opt = storage.slot0().observation_cardinality_next();
-        assert_eq!(
-            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1").unwrap())),
-            v_opt
-        );
-    }
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
 
-    #[test]
-    fn slot0_fee_protocol() {
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn fee_growth_inside0_last_x128(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(1);
+        let offset = 0;
+        let number_of_bytes = 32;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn fee_growth_inside1_last_x128(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(2);
+        let offset = 0;
+        let number_of_bytes = 32;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn tokens_owed0(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(3);
+        let offset = 0;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn tokens_owed1(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::from(3);
+        let offset = 16;
+        let number_of_bytes = 16;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_change(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct ObservationStruct<'a> {
+    pub storage_changes: Vec<&'a StorageChange>,
+    pub struct_slot: [u8; 32],
+}
+
+// An observation is always written as a whole, so unlike the other structs, a member is
+// returned as soon as its slot was written, even if its own value did not change.
+impl<'a> ObservationStruct<'a> {
+    pub fn new(storage_changes: Vec<&'a StorageChange>, struct_slot: [u8; 32]) -> ObservationStruct<'a> {
+        return Self {
+            struct_slot: struct_slot,
+            storage_changes: storage_changes,
+        };
+    }
+
+    // the block timestamp of the observation
+    pub fn block_timestamp(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::zero();
+        let offset = 0;
+        let number_of_bytes = 4;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_write(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // the tick accumulator, i.e. tick * time elapsed since the pool was first initialized
+    pub fn tick_cumulative(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::zero();
+        let offset = 4;
+        let number_of_bytes = 7;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_write(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_signed_bytes_be(old_data),
+                BigInt::from_signed_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // the seconds per liquidity, i.e. seconds elapsed / max(1, liquidity) since the pool was first initialized
+    pub fn seconds_per_liquidity_cumulative_x128(&self) -> Option<(BigInt, BigInt)> {
+        let slot = BigInt::zero();
+        let offset = 11;
+        let number_of_bytes = 20;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_write(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((
+                BigInt::from_unsigned_bytes_be(old_data),
+                BigInt::from_unsigned_bytes_be(new_data),
+            ))
+        } else {
+            None
+        }
+    }
+
+    // whether or not the observation is initialized, grown slots are written with initialized = false
+    pub fn initialized(&self) -> Option<(bool, bool)> {
+        let slot = BigInt::zero();
+        let offset = 31;
+        let number_of_bytes = 1;
+
+        let slot_key = utils::calc_struct_slot(&self.struct_slot, slot);
+
+        if let Some((old_data, new_data)) =
+            utils::get_storage_write(&self.storage_changes, slot_key, offset, number_of_bytes)
+        {
+            Some((old_data == [01u8], new_data == [01u8]))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::uniswap_v3_pool::UniswapPoolStorage;
+    use crate::storage::utils;
+    use std::collections::HashSet;
+    use std::ops::Add;
+    use std::str::FromStr;
+    use std::{fmt::Write, num::ParseIntError};
+    use substreams::scalar::BigInt;
+    use substreams::{hex, Hex};
+    use substreams_ethereum::pb::eth::v2::StorageChange;
+    use tiny_keccak::{Hasher, Keccak};
+
+    #[test]
+    fn slot0_sqrt_price_x96() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().sqrt_price_x96();
+        assert_eq!(
+            Some((
+                BigInt::from_str("0").unwrap(),
+                BigInt::from_str("8927094545831003674704908909").unwrap()
+            )),
+            v_opt
+        );
+    }
+
+    #[test]
+    fn slot0_tick() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().tick();
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("-43667").unwrap())),
+            v_opt
+        );
+    }
+
+    #[test]
+    fn slot0_observation_index() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().observation_index();
+        // going from 0 to 0 yields no cahnge
+        assert_eq!(None, v_opt);
+    }
+
+    #[test]
+    fn slot0_observation_cardinality() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().observation_cardinality();
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1").unwrap())),
+            v_opt
+        );
+    }
+
+    #[test]
+    fn slot0_observation_cardinality_next() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().observation_cardinality_next();
+        assert_eq!(
+            Some((BigInt::from_str("0").unwrap(), BigInt::from_str("1").unwrap())),
+            v_opt
+        );
+    }
+
+    #[test]
+    fn slot0_fee_protocol() {
         // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
         let storage_changes = vec![StorageChange {
             address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
@@ -531,6 +948,132 @@ mod tests {
         assert_eq!(Some((false, true)), v_opt);
     }
 
+    #[test]
+    fn tick_crossing_slots() {
+        let pool = hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec();
+        let storage_changes = vec![
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                old_value: hex!("000100000200020000b9c00000000000000000000005b5ae69d2e9b2f5be7ae8").to_vec(),
+                new_value: hex!("000100000200020000b9c40000000000000000000005b5ae69d2e9b2f5be7ae8").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("000000000000000000000000000000000000000000000000000000000000000a").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("0100000000000000000000000000000000000000000000000000000000000001").to_vec(),
+                ordinal: 1,
+            },
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("59d3454e6bb14d1f2ae9ab5d64a71e9d2d3eec41710c33f701d47eb206f29616").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("016091bfa60000000000000000314c3c8ef0a2c4b9b2ce9d0900000041d2241f").to_vec(),
+                ordinal: 2,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(&storage_changes, &pool);
+        let changed_map_slots = storage.changed_map_slots();
+        assert_eq!(
+            HashSet::from([hex!("59d3454e6bb14d1f2ae9ab5d64a71e9d2d3eec41710c33f701d47eb206f29616")]),
+            changed_map_slots
+        );
+
+        let crossed_tick = storage.ticks(&BigInt::from(193200));
+        assert!(crossed_tick
+            .crossing_slots()
+            .iter()
+            .any(|slot| changed_map_slots.contains(slot)));
+        let other_tick = storage.ticks(&BigInt::from(193260));
+        assert!(!other_tick
+            .crossing_slots()
+            .iter()
+            .any(|slot| changed_map_slots.contains(slot)));
+    }
+
+    #[test]
+    fn tick_info() {
+        // derived from: https://etherscan.io/tx/0x37d8f4b1b371fde9e4b1942588d16a1cbf424b7c66e731ec915aca785ca2efcf#statechange
+        let storage_changes = vec![
+            StorageChange {
+                address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+                key: hex!("59d3454e6bb14d1f2ae9ab5d64a71e9d2d3eec41710c33f701d47eb206f29613").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("000000000000000000008b61432d9e96000000000000000000008b61432d9e96").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+                key: hex!("59d3454e6bb14d1f2ae9ab5d64a71e9d2d3eec41710c33f701d47eb206f29616").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("016091bfa60000000000000000314c3c8ef0a2c4b9b2ce9d0900000041d2241f").to_vec(),
+                ordinal: 0,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+
+        let tick = storage.ticks(&BigInt::from(193200));
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(153249855151766_u64))),
+            tick.liquidity_gross()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(153249855151766_u64))),
+            tick.liquidity_net()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(1104290847))),
+            tick.tick_cumulative_outside()
+        );
+        assert_eq!(
+            Some((
+                BigInt::from(0),
+                BigInt::from_str("15256929822302888647438802185").unwrap()
+            )),
+            tick.seconds_per_liquidity_outside_x128()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(1620164518))),
+            tick.seconds_outside()
+        );
+        assert_eq!(None, tick.fee_growth_outside_1_x128());
+    }
+
+    #[test]
+    fn tick_info_negative_liquidity_net() {
+        // upper tick of a position: the liquidity is removed when crossing it going up
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("59d3454e6bb14d1f2ae9ab5d64a71e9d2d3eec41710c33f701d47eb206f29613").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            new_value: hex!("ffffffffffffffffffff749ebcd2616a000000000000000000008b61432d9e96").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+
+        let tick = storage.ticks(&BigInt::from(193200));
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(153249855151766_u64))),
+            tick.liquidity_gross()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(-153249855151766_i64))),
+            tick.liquidity_net()
+        );
+        assert_eq!(None, tick.seconds_outside());
+    }
+
     #[test]
     fn liquidity() {
         let storage_changes = vec![
@@ -569,6 +1112,276 @@ mod tests {
         );
     }
 
+    #[test]
+    fn slot0_observation_cardinality_next_above_i16() {
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            new_value: hex!("0001009c4000010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().observation_cardinality_next();
+        assert_eq!(Some((BigInt::from(1), BigInt::from(40000))), v_opt);
+    }
+
+    #[test]
+    fn slot0_fee_protocol_packed() {
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+            old_value: hex!("000100000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            new_value: hex!("0001a8000100010000ff556d00000000000000001cd851cd075726f0cf78926d").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        let v_opt = storage.slot0().fee_protocol();
+        // feeProtocol0 = 8 and feeProtocol1 = 10
+        assert_eq!(Some((BigInt::from(0), BigInt::from(168))), v_opt);
+    }
+
+    #[test]
+    fn protocol_fees() {
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000003").to_vec(),
+            old_value: hex!("0000000000000000000000000000000000000000000000000000000005f5e0ff").to_vec(),
+            new_value: hex!("00000000000000000de0b6b3a764000000000000000000000000000005f5e100").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        assert_eq!(
+            Some((BigInt::from(99999999), BigInt::from(100000000))),
+            storage.protocol_fees_token0()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from_str("1000000000000000000").unwrap())),
+            storage.protocol_fees_token1()
+        );
+    }
+
+    #[test]
+    fn observation_written() {
+        let storage_changes = vec![
+            StorageChange {
+                address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+                key: hex!("0000000000000000000000000000000000000000000000000000000000000004").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+                key: hex!("000000000000000000000000000000000000000000000000000000000000000a").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000001").to_vec(),
+                new_value: hex!("010000000000000000000000000000000000000123fffffffffffc186450c3a3").to_vec(),
+                ordinal: 1,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        assert_eq!(vec![2], storage.observation_indexes());
+
+        let observation = storage.observations(2);
+        assert_eq!(
+            Some((BigInt::from(1), BigInt::from(1683014563))),
+            observation.block_timestamp()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(-1000))),
+            observation.tick_cumulative()
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(291))),
+            observation.seconds_per_liquidity_cumulative_x128()
+        );
+        assert_eq!(Some((false, true)), observation.initialized());
+        assert_eq!(None, storage.observations(3).block_timestamp());
+    }
+
+    #[test]
+    fn observation_written_with_unchanged_member() {
+        // the ring buffer wrapped around on an observation holding the same tick cumulative
+        let storage_changes = vec![StorageChange {
+            address: hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+            key: hex!("0000000000000000000000000000000000000000000000000000000000000008").to_vec(),
+            old_value: hex!("010000000000000000000000000000000000000100fffffffffffc186450c000").to_vec(),
+            new_value: hex!("010000000000000000000000000000000000000123fffffffffffc186450c3a3").to_vec(),
+            ordinal: 0,
+        }];
+
+        let storage = UniswapPoolStorage::new(
+            &storage_changes,
+            &hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec(),
+        );
+        assert_eq!(vec![0], storage.observation_indexes());
+        assert_eq!(
+            Some((BigInt::from(-1000), BigInt::from(-1000))),
+            storage.observations(0).tick_cumulative()
+        );
+    }
+
+    #[test]
+    fn position_info() {
+        // full range position of the position manager, keyed by keccak256(owner, -887220, 887220)
+        let pool = hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec();
+        let storage_changes = vec![
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("1e6af8071088a01456f67390dba7029f946afa9f048993785717e0f8f105596f").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("0000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("1e6af8071088a01456f67390dba7029f946afa9f048993785717e0f8f1055972").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("00000000000000000000000000000007000000000000000000000000000003e8").to_vec(),
+                ordinal: 1,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(&storage_changes, &pool);
+        let position = storage.positions(
+            &hex!("c36442b4a4522e871399cd717abdd847ab11fe88").to_vec(),
+            -887220,
+            887220,
+        );
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(1000000000000000000_u64))),
+            position.liquidity()
+        );
+        assert_eq!(None, position.fee_growth_inside0_last_x128());
+        assert_eq!(Some((BigInt::from(0), BigInt::from(1000))), position.tokens_owed0());
+        assert_eq!(Some((BigInt::from(0), BigInt::from(7))), position.tokens_owed1());
+
+        let other_range = storage.positions(
+            &hex!("c36442b4a4522e871399cd717abdd847ab11fe88").to_vec(),
+            -887220,
+            887160,
+        );
+        assert_eq!(None, other_range.liquidity());
+    }
+
+    #[test]
+    fn tick_bitmap() {
+        // tick -887220 initialized in a pool with a tick spacing of 60: word -58, bit 61
+        let pool = hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec();
+        let storage_changes = vec![
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("1a01fdcbc6f05fa3889b8906cf915a5146123e92c71369e7ece115af72b51831").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("0000000000000000000000000000000000000000000000002000000000000000").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("50c245020e0d50943a7e4fa4dc17130bc2ba56c41463906c6b400a08276f2981").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("8000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                ordinal: 1,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(&storage_changes, &pool);
+        assert_eq!(
+            Some((BigInt::from(0), BigInt::from(2305843009213693952_u64))),
+            storage.tick_bitmap(-58)
+        );
+        // the highest tick of the word makes it negative
+        assert_eq!(
+            Some((
+                BigInt::from(0),
+                BigInt::from_str("-57896044618658097711785492504343953926634992332820282019728792003956564819968")
+                    .unwrap()
+            )),
+            storage.tick_bitmap(57)
+        );
+        assert_eq!(None, storage.tick_bitmap(0));
+    }
+
+    #[test]
+    fn position_info_fee_growth_inside() {
+        let pool = hex!("7858e59e0c01ea06df3af3d20ac7b0003275d4bf").to_vec();
+        let storage_changes = vec![
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("b3a1361c8c708fb3dabce7cdf27190c7488356413a2a3c1576a0ecfdebb75681").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("000000000000000000000000000000000000f1d3c1e2b8a0e8d6c5d4b3a29180").to_vec(),
+                ordinal: 0,
+            },
+            StorageChange {
+                address: pool.clone(),
+                key: hex!("b3a1361c8c708fb3dabce7cdf27190c7488356413a2a3c1576a0ecfdebb75682").to_vec(),
+                old_value: hex!("0000000000000000000000000000000000000000000000000000000000000000").to_vec(),
+                new_value: hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb").to_vec(),
+                ordinal: 1,
+            },
+        ];
+
+        let storage = UniswapPoolStorage::new(&storage_changes, &pool);
+        let position = storage.positions(&hex!("e592427a0aece92de3edee1f18e0157c05861564").to_vec(), -120, -60);
+        assert_eq!(None, position.liquidity());
+        assert_eq!(
+            Some((
+                BigInt::from(0),
+                BigInt::from_str("4904837861466576789622770100244864").unwrap()
+            )),
+            position.fee_growth_inside0_last_x128()
+        );
+        // the fee growths are uint256 which may wrap, they are decoded as unsigned
+        assert_eq!(
+            Some((
+                BigInt::from(0),
+                BigInt::from_str("115792089237316195423570985008687907853269984665640564039457584007913129639931")
+                    .unwrap()
+            )),
+            position.fee_growth_inside1_last_x128()
+        );
+        assert_eq!(None, position.tokens_owed0());
+        assert_eq!(None, position.tokens_owed1());
+    }
+
+    #[test]
+    fn position_slot_calc() {
+        // ticks are packed on 3 bytes: e592427a0aece92de3edee1f18e0157c05861564 ffff88 ffffc4
+        let position_key = utils::position_key(&hex!("e592427a0aece92de3edee1f18e0157c05861564").to_vec(), -120, -60);
+        assert_eq!(
+            "3f4b3961eccdbdce532d4e80dfa04e38e6326e05b19636c88b3f19de35a97093",
+            encode_hex(position_key.as_slice())
+        );
+
+        let positions_slot = utils::left_pad_from_bigint(&BigInt::from(7));
+        let position_struct_slot = utils::calc_map_slot(&position_key, &positions_slot);
+        assert_eq!(
+            "b3a1361c8c708fb3dabce7cdf27190c7488356413a2a3c1576a0ecfdebb75680",
+            encode_hex(position_struct_slot.as_slice())
+        );
+        assert_eq!(
+            "b3a1361c8c708fb3dabce7cdf27190c7488356413a2a3c1576a0ecfdebb75683",
+            encode_hex(utils::calc_struct_slot(&position_struct_slot, BigInt::from(3)).as_slice())
+        );
+    }
+
     #[test]
     fn slot_calc() {
         // slot of ticks map
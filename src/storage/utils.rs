@@ -32,6 +32,24 @@ pub fn get_storage_change<'a>(
     Some((old_data, new_data))
 }
 
+// Same as `get_storage_change`, but returns the last write to the slot even when the bytes we
+// read did not change. Used for structs that are always rewritten as a whole, like the oracle observations.
+pub fn get_storage_write<'a>(
+    storage_changes: &'a Vec<&StorageChange>,
+    slot_key: [u8; 32],
+    offset: usize,
+    number_of_bytes: usize,
+) -> Option<(&'a [u8], &'a [u8])> {
+    let storage = storage_changes
+        .iter()
+        .filter(|&&storage_change| storage_change.key.eq(slot_key.as_slice()))
+        .max_by(|x, y| x.ordinal.cmp(&y.ordinal))?;
+
+    let old_data = read_bytes(&storage.old_value, offset, number_of_bytes);
+    let new_data = read_bytes(&storage.new_value, offset, number_of_bytes);
+    Some((old_data, new_data))
+}
+
 pub fn calc_map_slot(map_index: &[u8; 32], base_slot: &[u8; 32]) -> [u8; 32] {
     let mut output = [0u8; 32];
     let mut hasher = Keccak::v256();
@@ -41,6 +59,22 @@ pub fn calc_map_slot(map_index: &[u8; 32], base_slot: &[u8; 32]) -> [u8; 32] {
     return output;
 }
 
+pub fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(input);
+    hasher.finalize(&mut output);
+    return output;
+}
+
+// key of the pool positions map: keccak256(abi.encodePacked(owner, tickLower, tickUpper)), the ticks being int24
+pub fn position_key(owner: &Vec<u8>, tick_lower: i32, tick_upper: i32) -> [u8; 32] {
+    let mut packed_key = contract_pad(owner).to_vec();
+    packed_key.extend_from_slice(&tick_lower.to_be_bytes()[1..]);
+    packed_key.extend_from_slice(&tick_upper.to_be_bytes()[1..]);
+    keccak256(&packed_key)
+}
+
 pub fn calc_struct_slot(struct_slot: &[u8; 32], member_slot: BigInt) -> [u8; 32] {
     let mut key = BigInt::from_signed_bytes_be(struct_slot.as_slice());
     key = key.add(member_slot);
@@ -0,0 +1,144 @@
+use crate::config;
+use crate::math::{MAX_TICK, MIN_TICK};
+use ethabi::ethereum_types::U256;
+
+/// Word and bit of a tick in the pool `tickBitmap`, like `TickBitmap.position`: the tick is compressed by the tick
+/// spacing, rounding towards negative infinity, the word holds 256 compressed ticks
+pub fn position(tick: i32, tick_spacing: i32) -> (i16, u8) {
+    let compressed = tick.div_euclid(tick_spacing);
+    ((compressed >> 8) as i16, (compressed & 0xff) as u8)
+}
+
+/// Every word a pool with this tick spacing can have, from the one of `MIN_TICK` to the one of `MAX_TICK`
+pub fn word_positions(tick_spacing: i32) -> std::ops::RangeInclusive<i16> {
+    position(MIN_TICK, tick_spacing).0..=position(MAX_TICK, tick_spacing).0
+}
+
+/// The initialized ticks of a bitmap word, in ascending order
+pub fn initialized_ticks(word_pos: i16, word: U256, tick_spacing: i32) -> Vec<i32> {
+    (0..256)
+        .filter(|bit_pos| word.bit(*bit_pos))
+        .map(|bit_pos| ((word_pos as i32) * 256 + bit_pos as i32) * tick_spacing)
+        .collect()
+}
+
+/// The greatest initialized tick at or below `tick`, like `TickBitmap.nextInitializedTickWithinOneWord` going down
+/// but carrying on over at most `max_words` words, each read with `word`. The words never written are empty.
+pub fn initialized_tick_at_or_below<F>(tick: i32, tick_spacing: i32, max_words: usize, word: F) -> Option<i32>
+where
+    F: Fn(i16) -> Option<U256>,
+{
+    let (mut word_pos, bit_pos) = position(tick, tick_spacing);
+    let lowest_word_pos = *word_positions(tick_spacing).start();
+    // only the bits at or below the tick in its own word
+    let mut mask = if bit_pos == 255 {
+        U256::MAX
+    } else {
+        (U256::one() << (bit_pos as usize + 1)) - 1
+    };
+    for _ in 0..max_words {
+        if word_pos < lowest_word_pos {
+            break;
+        }
+        let masked = word(word_pos).unwrap_or_default() & mask;
+        if !masked.is_zero() {
+            let bit_pos = masked.bits() - 1;
+            return Some(((word_pos as i32) * 256 + bit_pos as i32) * tick_spacing);
+        }
+        word_pos -= 1;
+        mask = U256::MAX;
+    }
+    None
+}
+
+/// Reads the `pools` param, a comma separated list of pool addresses (ex: `pools=0x8ad5...,0x88e6...`)
+pub fn parse_pools(params: &String) -> Vec<String> {
+    config::split_params(params)
+        .filter(|(key, _)| *key == "pools")
+        .flat_map(|(_, value)| value.split(","))
+        .map(|pool| pool.trim().trim_start_matches("0x").to_lowercase())
+        .filter(|pool| !pool.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn tick_positions() {
+        assert_eq!((0, 0), position(0, 1));
+        assert_eq!((0, 255), position(255, 1));
+        assert_eq!((1, 0), position(256, 1));
+        assert_eq!((-1, 255), position(-1, 1));
+        assert_eq!((-1, 0), position(-256, 1));
+        assert_eq!((-2, 255), position(-257, 1));
+        // compressed towards negative infinity
+        assert_eq!((0, 1), position(60, 60));
+        assert_eq!((-1, 255), position(-60, 60));
+        assert_eq!((-1, 255), position(-30, 60));
+        assert_eq!((-58, 61), position(-887220, 60));
+    }
+
+    #[test]
+    fn words_of_the_tick_range() {
+        assert_eq!(-3466..=3465, word_positions(1));
+        assert_eq!(-347..=346, word_positions(10));
+        assert_eq!(-58..=57, word_positions(60));
+        assert_eq!(-18..=17, word_positions(200));
+    }
+
+    #[test]
+    fn initialized_tick_at_or_below_a_tick() {
+        let words: HashMap<i16, U256> = HashMap::from([
+            (0, U256::from(3) | (U256::one() << 200)),
+            (-1, U256::one() << 255),
+            (-5, U256::one() << 4),
+        ]);
+        let word = |word_pos: i16| words.get(&word_pos).cloned();
+
+        assert_eq!(Some(0), initialized_tick_at_or_below(0, 60, 1, word));
+        assert_eq!(Some(60), initialized_tick_at_or_below(119, 60, 1, word));
+        assert_eq!(Some(12000), initialized_tick_at_or_below(15359, 60, 1, word));
+        assert_eq!(Some(-60), initialized_tick_at_or_below(-1, 60, 1, word));
+        // the empty words below are skipped, within the lookups
+        assert_eq!(Some(-76560), initialized_tick_at_or_below(-61, 60, 5, word));
+        assert_eq!(None, initialized_tick_at_or_below(-61, 60, 4, word));
+        // nothing below the lowest word of the tick range
+        assert_eq!(None, initialized_tick_at_or_below(-76561, 60, 1000, word));
+    }
+
+    #[test]
+    fn ticks_of_a_word() {
+        assert_eq!(Vec::<i32>::new(), initialized_ticks(0, U256::zero(), 60));
+        assert_eq!(
+            vec![0, 60, 15300],
+            initialized_ticks(0, U256::from(3) | (U256::one() << 255), 60)
+        );
+        assert_eq!(
+            vec![-15360, -60],
+            initialized_ticks(-1, U256::one() | (U256::one() << 255), 60)
+        );
+
+        let (word_pos, bit_pos) = position(-887220, 60);
+        assert_eq!(
+            vec![-887220],
+            initialized_ticks(word_pos, U256::one() << bit_pos as usize, 60)
+        );
+    }
+
+    #[test]
+    fn pools_param() {
+        assert_eq!(Vec::<String>::new(), parse_pools(&"".to_string()));
+
+        let params = "network=mainnet&pools=0x8AD599C3A0FF1DE082011EFDDC58F1908EB6E6D8, 0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640";
+        assert_eq!(
+            vec![
+                "8ad599c3a0ff1de082011efddc58f1908eb6e6d8".to_string(),
+                "88e6a0c2ddd26feeb64f039a2c41296fcb3f5640".to_string()
+            ],
+            parse_pools(&params.to_string())
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use crate::math;
+use ethabi::ethereum_types::U256;
+use std::ops::Div;
+use substreams::scalar::BigDecimal;
+
+// upper bound of the `tickBitmap` words read to find the range a swap starts or ends in
+pub const MAX_BITMAP_WORD_LOOKUPS: usize = 8;
+
+/// Splits a swap over the active liquidity ranges it went through, each range being identified by its lower
+/// initialized tick. The swap is cut at the ticks it crossed, given in the swap direction with their `liquidityNet`,
+/// and each segment gets the amounts of token0 and token1 swapped at the liquidity active over it. The liquidity of
+/// the segments is rebuilt backwards from the pool liquidity after the swap. The range of the segment below the
+/// lowest crossed tick is `lowest_range_start`, the initialized tick at or below the lowest tick of the swap; when
+/// it is unknown, that segment is left out and the swap is split over the other ranges.
+///
+/// Returns the ranges with their share of the token0 and token1 amounts of the swap.
+pub fn traversed_ranges(
+    sqrt_price_before: U256,
+    sqrt_price_after: U256,
+    liquidity_after: i128,
+    crossed_ticks: &[(i32, i128)],
+    lowest_range_start: Option<i32>,
+) -> Vec<(i32, BigDecimal, BigDecimal)> {
+    let up = sqrt_price_after > sqrt_price_before;
+    let segments_count = crossed_ticks.len() + 1;
+
+    let mut sqrt_prices = vec![sqrt_price_before];
+    sqrt_prices.extend(
+        crossed_ticks
+            .iter()
+            .map(|(tick_idx, _)| math::get_sqrt_ratio_at_tick(*tick_idx)),
+    );
+    sqrt_prices.push(sqrt_price_after);
+
+    // crossing a tick up adds its net liquidity, crossing it down removes it
+    let mut liquidities = vec![liquidity_after; segments_count];
+    for i in (0..crossed_ticks.len()).rev() {
+        let liquidity_net = crossed_ticks[i].1;
+        liquidities[i] = if up {
+            liquidities[i + 1] - liquidity_net
+        } else {
+            liquidities[i + 1] + liquidity_net
+        };
+    }
+
+    let mut amounts = vec![];
+    for i in 0..segments_count {
+        let range_start = if up {
+            match i {
+                0 => lowest_range_start,
+                _ => Some(crossed_ticks[i - 1].0),
+            }
+        } else {
+            match crossed_ticks.get(i) {
+                Some((tick_idx, _)) => Some(*tick_idx),
+                None => lowest_range_start,
+            }
+        };
+        let range_start = match range_start {
+            Some(range_start) => range_start,
+            None => continue,
+        };
+        let liquidity = U256::from(liquidities[i].max(0) as u128);
+        amounts.push((
+            range_start,
+            math::get_amount0_for_liquidity(sqrt_prices[i], sqrt_prices[i + 1], liquidity),
+            math::get_amount1_for_liquidity(sqrt_prices[i], sqrt_prices[i + 1], liquidity),
+        ));
+    }
+
+    let total0 = amounts
+        .iter()
+        .fold(U256::zero(), |total, (_, amount0, _)| total + *amount0);
+    let total1 = amounts
+        .iter()
+        .fold(U256::zero(), |total, (_, _, amount1)| total + *amount1);
+    if total0.is_zero() && total1.is_zero() {
+        // a swap which did not move the price belongs entirely to the range it happened in
+        return match amounts.first() {
+            Some((range_start, _, _)) => vec![(*range_start, BigDecimal::one(), BigDecimal::one())],
+            None => vec![],
+        };
+    }
+
+    amounts
+        .into_iter()
+        .filter(|(_, amount0, amount1)| !amount0.is_zero() || !amount1.is_zero())
+        .map(|(range_start, amount0, amount1)| (range_start, share(amount0, total0), share(amount1, total1)))
+        .collect()
+}
+
+fn share(amount: U256, total: U256) -> BigDecimal {
+    if total.is_zero() {
+        return BigDecimal::zero();
+    }
+    BigDecimal::try_from(amount.to_string().as_str())
+        .unwrap()
+        .div(BigDecimal::try_from(total.to_string().as_str()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::get_sqrt_ratio_at_tick;
+    use crate::tick_ranges::traversed_ranges;
+    use ethabi::ethereum_types::U256;
+    use std::ops::Div;
+    use substreams::scalar::BigDecimal;
+
+    fn share(numerator: i64, denominator: i64) -> BigDecimal {
+        BigDecimal::from(numerator).div(BigDecimal::from(denominator))
+    }
+
+    // 2^96 is the sqrt price of the tick 0
+    fn sqrt_price(numerator: u64, denominator: u64) -> U256 {
+        (U256::one() << 96) * numerator / denominator
+    }
+
+    #[test]
+    fn swap_within_a_range() {
+        let ranges = traversed_ranges(sqrt_price(1, 1), sqrt_price(1, 1), 1_000_000, &[], Some(0));
+        assert_eq!(vec![(0, BigDecimal::one(), BigDecimal::one())], ranges);
+
+        let ranges = traversed_ranges(sqrt_price(1, 1), sqrt_price(3, 2), 1_000_000, &[], Some(0));
+        assert_eq!(vec![(0, BigDecimal::one(), BigDecimal::one())], ranges);
+    }
+
+    #[test]
+    fn swap_crossing_up() {
+        // from 1/2 to 3/2 of 2^96, crossing the tick 0 which doubles the liquidity of L = 3_000_000:
+        // token0: L over the first segment and 2L/3 over the second one
+        // token1: L/2 over the first segment and L over the second one
+        let ranges = traversed_ranges(
+            sqrt_price(1, 2),
+            sqrt_price(3, 2),
+            6_000_000,
+            &[(0, 3_000_000)],
+            Some(-60),
+        );
+        assert_eq!(
+            vec![(-60, share(3, 5), share(1, 3)), (0, share(2, 5), share(2, 3))],
+            ranges
+        );
+    }
+
+    #[test]
+    fn swap_crossing_down() {
+        // the same swap the other way around, the tick 0 removes the liquidity it added going up
+        let ranges = traversed_ranges(
+            sqrt_price(3, 2),
+            sqrt_price(1, 2),
+            3_000_000,
+            &[(0, 3_000_000)],
+            Some(-60),
+        );
+        assert_eq!(
+            vec![(0, share(2, 5), share(2, 3)), (-60, share(3, 5), share(1, 3))],
+            ranges
+        );
+    }
+
+    #[test]
+    fn swap_crossing_several_ticks_down() {
+        let ranges = traversed_ranges(
+            sqrt_price(2, 1),
+            sqrt_price(1, 2),
+            1_000_000,
+            &[(6000, 500_000), (0, -250_000)],
+            Some(-6960),
+        );
+        let range_starts: Vec<i32> = ranges.iter().map(|(range_start, _, _)| *range_start).collect();
+        assert_eq!(vec![6000, 0, -6960], range_starts);
+        let token1_total = ranges.iter().fold(BigDecimal::zero(), |total, (_, _, token1_share)| {
+            total + token1_share.clone()
+        });
+        assert_eq!(BigDecimal::one(), token1_total.with_prec(20));
+    }
+
+    #[test]
+    fn swap_ending_on_a_crossed_tick() {
+        let ranges = traversed_ranges(
+            sqrt_price(1, 2),
+            get_sqrt_ratio_at_tick(0),
+            3_000_000,
+            &[(0, 3_000_000)],
+            Some(-60),
+        );
+        assert_eq!(vec![(-60, BigDecimal::one(), BigDecimal::one())], ranges);
+    }
+
+    #[test]
+    fn unknown_lowest_range() {
+        // the segment below the crossed tick is left out
+        let ranges = traversed_ranges(sqrt_price(1, 2), sqrt_price(3, 2), 6_000_000, &[(0, 3_000_000)], None);
+        assert_eq!(vec![(0, BigDecimal::one(), BigDecimal::one())], ranges);
+
+        let ranges = traversed_ranges(sqrt_price(1, 1), sqrt_price(3, 2), 1_000_000, &[], None);
+        assert_eq!(Vec::<(i32, BigDecimal, BigDecimal)>::new(), ranges);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::config;
+use crate::pb::uniswap::events::Observation;
+
+// averaging windows, in seconds, used when the module params do not define any
+pub const DEFAULT_WINDOWS: [u32; 2] = [1800, 3600];
+
+pub struct ObservationPoint {
+    pub block_timestamp: u64,
+    pub tick_cumulative: i64,
+    pub initialized: bool,
+}
+
+impl From<&Observation> for ObservationPoint {
+    fn from(observation: &Observation) -> Self {
+        ObservationPoint {
+            block_timestamp: observation.block_timestamp as u64,
+            tick_cumulative: observation.tick_cumulative.parse::<i64>().unwrap(),
+            initialized: observation.initialized,
+        }
+    }
+}
+
+/// Reads the `windows` param, a comma separated list of seconds (ex: `windows=1800,3600`)
+pub fn parse_windows(params: &String) -> Vec<u32> {
+    let windows: Vec<u32> = config::split_params(params)
+        .filter(|(key, _)| *key == "windows")
+        .flat_map(|(_, value)| value.split(","))
+        .filter(|window| !window.trim().is_empty())
+        .map(|window| {
+            window
+                .trim()
+                .parse::<u32>()
+                .unwrap_or_else(|_| panic!("invalid twap window {window}"))
+        })
+        .collect();
+
+    if windows.is_empty() {
+        return DEFAULT_WINDOWS.to_vec();
+    }
+    windows
+}
+
+/// Arithmetic mean tick over the last `window` seconds, rounded to negative infinity like `OracleLibrary.consult`.
+/// Returns None when the oracle does not hold observations going back far enough.
+pub fn arithmetic_mean_tick<F>(
+    time: u64,
+    window: u32,
+    tick: i32,
+    index: u32,
+    cardinality: u32,
+    observation_at: F,
+) -> Option<i32>
+where
+    F: Fn(u32) -> Option<ObservationPoint>,
+{
+    if window == 0 {
+        return None;
+    }
+    let tick_cumulative_now = observe_single(time, 0, tick, index, cardinality, &observation_at)?;
+    let tick_cumulative_then = observe_single(time, window, tick, index, cardinality, &observation_at)?;
+
+    Some(mean_tick(tick_cumulative_now - tick_cumulative_then, window))
+}
+
+fn mean_tick(tick_cumulative_delta: i64, window: u32) -> i32 {
+    let window = window as i64;
+    let mut mean = tick_cumulative_delta / window;
+    if tick_cumulative_delta < 0 && tick_cumulative_delta % window != 0 {
+        mean -= 1;
+    }
+    mean as i32
+}
+
+/// Port of `Oracle.observeSingle`: the tick cumulative `seconds_ago` seconds before `time`
+pub fn observe_single<F>(
+    time: u64,
+    seconds_ago: u32,
+    tick: i32,
+    index: u32,
+    cardinality: u32,
+    observation_at: &F,
+) -> Option<i64>
+where
+    F: Fn(u32) -> Option<ObservationPoint>,
+{
+    if cardinality == 0 {
+        return None;
+    }
+    let target = time.checked_sub(seconds_ago as u64)?;
+
+    let last = observation_at(index)?;
+    if last.block_timestamp <= target {
+        // no observation was written since the target, so the tick did not move
+        return Some(transform(&last, target, tick));
+    }
+
+    // the oldest observation is the next one in the ring buffer, or index 0 when the buffer was not filled yet
+    let oldest = match observation_at((index + 1) % cardinality) {
+        Some(observation) if observation.initialized => observation,
+        _ => observation_at(0)?,
+    };
+    if oldest.block_timestamp > target {
+        return None;
+    }
+
+    let (before_or_at, at_or_after) = binary_search(target, index, cardinality, observation_at)?;
+    if target == before_or_at.block_timestamp {
+        return Some(before_or_at.tick_cumulative);
+    }
+    if target == at_or_after.block_timestamp {
+        return Some(at_or_after.tick_cumulative);
+    }
+
+    let observation_time_delta = (at_or_after.block_timestamp - before_or_at.block_timestamp) as i64;
+    let target_delta = (target - before_or_at.block_timestamp) as i64;
+    Some(
+        before_or_at.tick_cumulative
+            + ((at_or_after.tick_cumulative - before_or_at.tick_cumulative) / observation_time_delta) * target_delta,
+    )
+}
+
+fn transform(last: &ObservationPoint, target: u64, tick: i32) -> i64 {
+    last.tick_cumulative + tick as i64 * (target - last.block_timestamp) as i64
+}
+
+fn binary_search<F>(
+    target: u64,
+    index: u32,
+    cardinality: u32,
+    observation_at: &F,
+) -> Option<(ObservationPoint, ObservationPoint)>
+where
+    F: Fn(u32) -> Option<ObservationPoint>,
+{
+    let mut l = ((index + 1) % cardinality) as u64;
+    let mut r = l + cardinality as u64 - 1;
+
+    while l <= r {
+        let i = (l + r) / 2;
+        let before_or_at = match observation_at((i % cardinality as u64) as u32) {
+            Some(observation) if observation.initialized => observation,
+            _ => {
+                l = i + 1;
+                continue;
+            }
+        };
+        let at_or_after = observation_at(((i + 1) % cardinality as u64) as u32)?;
+
+        let target_at_or_after = before_or_at.block_timestamp <= target;
+        if target_at_or_after && target <= at_or_after.block_timestamp {
+            return Some((before_or_at, at_or_after));
+        }
+
+        if !target_at_or_after {
+            r = i.checked_sub(1)?;
+        } else {
+            l = i + 1;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::twap::{arithmetic_mean_tick, mean_tick, observe_single, parse_windows, ObservationPoint};
+
+    // (block_timestamp, tick_cumulative), ticks were 10, -30 then 20
+    fn observation_at(observations: &Vec<Option<(u64, i64)>>, index: u32) -> Option<ObservationPoint> {
+        observations
+            .get(index as usize)
+            .cloned()
+            .flatten()
+            .map(|(block_timestamp, tick_cumulative)| ObservationPoint {
+                block_timestamp,
+                tick_cumulative,
+                initialized: true,
+            })
+    }
+
+    #[test]
+    fn observe_current_and_past() {
+        let observations = vec![
+            Some((1000, 0)),
+            Some((1100, 1000)),
+            Some((1300, -5000)),
+            Some((1400, -3000)),
+        ];
+        let get = |index| observation_at(&observations, index);
+
+        assert_eq!(Some(2000), observe_single(1500, 0, 50, 3, 4, &get));
+        assert_eq!(Some(-500), observe_single(1500, 50, 50, 3, 4, &get));
+        assert_eq!(Some(-3000), observe_single(1500, 100, 50, 3, 4, &get));
+        assert_eq!(Some(-2000), observe_single(1500, 300, 50, 3, 4, &get));
+        assert_eq!(Some(1000), observe_single(1500, 400, 50, 3, 4, &get));
+        assert_eq!(None, observe_single(1500, 600, 50, 3, 4, &get));
+    }
+
+    #[test]
+    fn mean_tick_over_windows() {
+        let observations = vec![
+            Some((1000, 0)),
+            Some((1100, 1000)),
+            Some((1300, -5000)),
+            Some((1400, -3000)),
+        ];
+        let get = |index| observation_at(&observations, index);
+
+        assert_eq!(Some(50), arithmetic_mean_tick(1500, 50, 50, 3, 4, get));
+        assert_eq!(Some(13), arithmetic_mean_tick(1500, 300, 50, 3, 4, get));
+        assert_eq!(Some(2), arithmetic_mean_tick(1500, 400, 50, 3, 4, get));
+        assert_eq!(None, arithmetic_mean_tick(1500, 600, 50, 3, 4, get));
+    }
+
+    #[test]
+    fn observe_wrapped_ring_buffer() {
+        let observations = vec![Some((1400, -3000)), Some((1100, 1000)), Some((1300, -5000))];
+        let get = |index| observation_at(&observations, index);
+
+        assert_eq!(Some(-2000), observe_single(1500, 300, 50, 0, 3, &get));
+        assert_eq!(Some(13), arithmetic_mean_tick(1500, 300, 50, 0, 3, get));
+    }
+
+    #[test]
+    fn observe_with_grown_cardinality() {
+        // cardinality was grown to 4 but only the first two observations were written
+        let observations = vec![Some((1000, 0)), Some((1100, 1000)), None, None];
+        let get = |index| observation_at(&observations, index);
+
+        assert_eq!(Some(500), observe_single(1500, 450, 50, 1, 4, &get));
+        assert_eq!(Some(45), arithmetic_mean_tick(1500, 450, 50, 1, 4, get));
+    }
+
+    #[test]
+    fn mean_tick_rounds_to_negative_infinity() {
+        assert_eq!(-4, mean_tick(-7, 2));
+        assert_eq!(-3, mean_tick(-6, 2));
+        assert_eq!(3, mean_tick(7, 2));
+    }
+
+    #[test]
+    fn windows_from_params() {
+        assert_eq!(vec![1800, 3600], parse_windows(&"".to_string()));
+        assert_eq!(vec![60, 600], parse_windows(&"windows=60,600".to_string()));
+        assert_eq!(vec![300], parse_windows(&"other=1&windows=300".to_string()));
+    }
+}
@@ -1,8 +1,9 @@
+use crate::config::PricingConfig;
 use crate::ethpb::v2::TransactionTrace;
 use crate::pb::uniswap::events;
 use crate::pb::AdjustedAmounts;
 use crate::uniswap::events::Transaction;
-use crate::{storage, Erc20Token, StorageChange, WHITELIST_TOKENS};
+use crate::{storage, StorageChange};
 use std::ops::{Add, Mul};
 use std::string::ToString;
 use substreams::prelude::StoreGetBigDecimal;
@@ -10,74 +11,7 @@ use substreams::scalar::{BigDecimal, BigInt};
 use substreams::store::StoreGet;
 use substreams::{hex, log, Hex};
 
-pub const UNISWAP_V3_FACTORY: [u8; 20] = hex!("1f98431c8ad98523631ae4a59f267346ea31f984");
-
 pub const ZERO_ADDRESS: [u8; 20] = hex!("0000000000000000000000000000000000000000");
-pub const NON_FUNGIBLE_POSITION_MANAGER: [u8; 20] = hex!("c36442b4a4522e871399cd717abdd847ab11fe88");
-pub const ERROR_POOL: [u8; 20] = hex!("8fe8d9bb8eeba3ed688069c3d6b556c9ca258248");
-
-const DGD_TOKEN_ADDRESS: [u8; 20] = hex!("e0b7927c4af23765cb51314a0e0521a9645f0e2a");
-const AAVE_TOKEN_ADDRESS: [u8; 20] = hex!("7fc66500c84a76ad7e9c93437bfc5ac33e2ddae9");
-const LIF_TOKEN_ADDRESS: [u8; 20] = hex!("eb9951021698b42e4399f9cbb6267aa35f82d59d");
-const SVD_TOKEN_ADDRESS: [u8; 20] = hex!("bdeb4b83251fb146687fa19d1c660f99411eefe3");
-const THEDAO_TOKEN_ADDRESS: [u8; 20] = hex!("bb9bc244d798123fde783fcc1c72d3bb8c189413");
-const HPB_TOKEN_ADDRESS: [u8; 20] = hex!("38c6a68304cdefb9bec48bbfaaba5c5b47818bb2");
-
-// hard-coded tokens which have various behaviours but for which a UniswapV3 valid pool
-// exists, some are tokens which were migrated to new addresses
-pub fn get_static_uniswap_tokens(token_address: &[u8]) -> Option<Erc20Token> {
-    match token_address {
-        x if x == DGD_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&DGD_TOKEN_ADDRESS).to_string(),
-            name: "DGD".to_string(),
-            symbol: "DGD".to_string(),
-            decimals: 9,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        x if x == AAVE_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&AAVE_TOKEN_ADDRESS).to_string(),
-            name: "Aave Token".to_string(),
-            symbol: "AAVE".to_string(),
-            decimals: 18,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        x if x == LIF_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&LIF_TOKEN_ADDRESS).to_string(),
-            name: "LIF".to_string(),
-            symbol: "LIF".to_string(),
-            decimals: 18,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        x if x == SVD_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&SVD_TOKEN_ADDRESS).to_string(),
-            name: "savedroid".to_string(),
-            symbol: "SVD".to_string(),
-            decimals: 18,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        x if x == THEDAO_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&THEDAO_TOKEN_ADDRESS).to_string(),
-            name: "TheDAO".to_string(),
-            symbol: "TheDAO".to_string(),
-            decimals: 16,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        x if x == HPB_TOKEN_ADDRESS => Some(Erc20Token {
-            address: Hex(&HPB_TOKEN_ADDRESS).to_string(),
-            name: "HPBCoin".to_string(),
-            symbol: "HPB".to_string(),
-            decimals: 18,
-            total_supply: "".to_string(), // subgraph doesn't check the total supply
-            whitelist_pools: vec![],
-        }),
-        _ => None,
-    }
-}
 
 pub fn extract_pool_fee_growth_global_updates(
     log_ordinal: u64,
@@ -134,6 +68,7 @@ pub fn get_tracked_amount_usd(
     amount0_abs: &BigDecimal,
     amount1_abs: &BigDecimal,
     eth_price_in_usd: &BigDecimal,
+    config: &PricingConfig,
 ) -> BigDecimal {
     let price0_usd = token0_derived_eth_price.clone().mul(eth_price_in_usd.clone());
     let price1_usd = token1_derived_eth_price.clone().mul(eth_price_in_usd.clone());
@@ -142,7 +77,7 @@ pub fn get_tracked_amount_usd(
     log::info!("price1_usd: {}", price1_usd);
 
     // both are whitelist tokens, return sum of both amounts
-    if WHITELIST_TOKENS.contains(&token0_id.as_str()) && WHITELIST_TOKENS.contains(&token1_id.as_str()) {
+    if config.is_whitelisted(token0_id) && config.is_whitelisted(token1_id) {
         return amount0_abs
             .clone()
             .mul(price0_usd)
@@ -150,12 +85,12 @@ pub fn get_tracked_amount_usd(
     }
 
     // take double value of the whitelisted token amount
-    if WHITELIST_TOKENS.contains(&token0_id.as_str()) && !WHITELIST_TOKENS.contains(&token1_id.as_str()) {
+    if config.is_whitelisted(token0_id) && !config.is_whitelisted(token1_id) {
         return amount0_abs.clone().mul(price0_usd).mul(BigDecimal::from(2 as i32));
     }
 
     // take double value of the whitelisted token amount
-    if !WHITELIST_TOKENS.contains(&token0_id.as_str()) && WHITELIST_TOKENS.contains(&token1_id.as_str()) {
+    if !config.is_whitelisted(token0_id) && config.is_whitelisted(token1_id) {
         return amount1_abs.clone().mul(price1_usd).mul(BigDecimal::from(2 as i32));
     }
 
@@ -171,6 +106,7 @@ pub fn get_adjusted_amounts(
     token0_derived_eth_price: &BigDecimal,
     token1_derived_eth_price: &BigDecimal,
     bundle_eth_price_usd: &BigDecimal,
+    config: &PricingConfig,
 ) -> AdjustedAmounts {
     log::info!("token0_addr {:}", token0_addr);
     log::info!("token1_addr {:}", token1_addr);
@@ -193,18 +129,18 @@ pub fn get_adjusted_amounts(
         .mul(token0_derived_eth_price.clone())
         .add(token1_amount.clone().mul(token1_derived_eth_price.clone()));
 
-    if WHITELIST_TOKENS.contains(&token0_addr.as_str()) && WHITELIST_TOKENS.contains(&token1_addr.as_str()) {
+    if config.is_whitelisted(token0_addr) && config.is_whitelisted(token1_addr) {
         eth = eth_untracked.clone()
     }
 
-    if WHITELIST_TOKENS.contains(&token0_addr.as_str()) && !WHITELIST_TOKENS.contains(&token1_addr.as_str()) {
+    if config.is_whitelisted(token0_addr) && !config.is_whitelisted(token1_addr) {
         eth = token0_amount
             .clone()
             .mul(token0_derived_eth_price.clone())
             .mul(BigDecimal::from(2 as i32));
     }
 
-    if !WHITELIST_TOKENS.contains(&token0_addr.as_str()) && WHITELIST_TOKENS.contains(&token1_addr.as_str()) {
+    if !config.is_whitelisted(token0_addr) && config.is_whitelisted(token1_addr) {
         eth = token1_amount
             .clone()
             .mul(token1_derived_eth_price.clone())